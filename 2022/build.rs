@@ -0,0 +1,3 @@
+fn main() {
+    aoc::sample::write_tests("input");
+}
@@ -1,6 +1,4 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use aoc::{ParseError, Solution};
 
 #[derive(Debug)]
 struct Elf {
@@ -17,20 +15,30 @@ impl Elf {
     }
 }
 
-fn main() {
-    let f = File::open("input/001.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let elves = parse_elves(lines);
-    let mut inventory: Vec<u32> = elves.iter().map(|elf| elf.total_snacks()).collect();
-    println!("max is {:?}", inventory.iter().max());
+pub struct Day001 {
+    elves: Vec<Elf>,
+}
+
+impl Solution for Day001 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day001 {
+            elves: parse_elves(aoc::lines(input)),
+        })
+    }
 
-    inventory.sort();
-    inventory.reverse();
-    println!("top 3 is {:?}", inventory[0..3].iter().sum::<u32>());
+    fn part1(&self) -> u32 {
+        self.elves.iter().map(|elf| elf.total_snacks()).max().unwrap_or(0)
+    }
+
+    fn part2(&self) -> u32 {
+        let mut inventory: Vec<u32> = self.elves.iter().map(|elf| elf.total_snacks()).collect();
+        inventory.sort();
+        inventory.reverse();
+        inventory.iter().take(3).sum()
+    }
 }
 
 fn parse_elves(lines: Vec<String>) -> Vec<Elf> {
@@ -53,7 +61,7 @@ fn parse_elves(lines: Vec<String>) -> Vec<Elf> {
 
 #[cfg(test)]
 mod tests {
-    use crate::parse_elves;
+    use super::parse_elves;
     #[test]
     fn test_parse() {
         let elves = parse_elves(
@@ -1,7 +1,5 @@
+use aoc::{ParseError, Solution};
 use std::cmp::Ordering::{Equal, Greater, Less};
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 #[derive(Copy, Clone, PartialEq)]
 enum Move {
@@ -114,17 +112,27 @@ impl Score for Guide {
     }
 }
 
-fn main() {
-    let f = File::open("input/002.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<String> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let guide = parse_guide(Game::bad_parse, lines.clone());
-    println!("follow the wrong guide for a score of: {}", guide.score());
-    let guide = parse_guide(Game::parse, lines);
-    println!("follow the secret guide for a score of: {}", guide.score());
+pub struct Day002 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day002 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day002 {
+            lines: aoc::lines(input),
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        parse_guide(Game::bad_parse, self.lines.clone()).score()
+    }
+
+    fn part2(&self) -> u32 {
+        parse_guide(Game::parse, self.lines.clone()).score()
+    }
 }
 
 fn parse_guide(parser: fn(String) -> Game, lines: Vec<String>) -> Guide {
@@ -137,9 +145,9 @@ fn parse_guide(parser: fn(String) -> Game, lines: Vec<String>) -> Guide {
 
 #[cfg(test)]
 mod tests {
-    use crate::parse_guide;
-    use crate::Game;
-    use crate::Score;
+    use super::parse_guide;
+    use super::Game;
+    use super::Score;
     #[test]
     fn test_bad_parse() {
         let guide = parse_guide(Game::bad_parse, ["A Y", "B X", "C Z"].map(String::from).to_vec());
@@ -1,6 +1,6 @@
+use aoc::{ParseError, Solution};
 use std::collections::HashSet;
 use std::panic;
-use std::{fs::File, io::Read};
 
 #[derive(Debug)]
 struct Rucksack {
@@ -81,20 +81,32 @@ fn compute_group_priorities(rucksacks: &[Rucksack]) -> u32 {
     total
 }
 
-fn main() {
-    let mut f = File::open("input/003.txt").expect("File Error");
-    let mut input = String::new();
-    f.read_to_string(&mut input).expect("File Read Error");
-    let rucksacks = parse_rucksacks(&input);
-    let total: u32 = total_priority(&rucksacks);
-    println!("total priority: {total}");
-    let group_total: u32 = compute_group_priorities(&rucksacks);
-    println!("total group priority: {group_total}");
+pub struct Day003 {
+    rucksacks: Vec<Rucksack>,
+}
+
+impl Solution for Day003 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day003 {
+            rucksacks: parse_rucksacks(input),
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        total_priority(&self.rucksacks)
+    }
+
+    fn part2(&self) -> u32 {
+        compute_group_priorities(&self.rucksacks)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
 jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
 PmmdzqPrVvPwwTWBwg
@@ -0,0 +1,142 @@
+use aoc::{parse_line, parse_lines, ParseError, Solution};
+
+parse_line! {
+    struct Assignment {
+        a_lo: u32,
+        a_hi: u32,
+        b_lo: u32,
+        b_hi: u32,
+    } = "{a_lo}-{a_hi},{b_lo}-{b_hi}";
+}
+
+type Job = (u32, u32);
+type ElfPair = (Job, Job);
+
+fn contained_in(a: &Job, b: &Job) -> bool {
+    a.0 >= b.0 && a.1 <= b.1
+}
+
+fn overlapping(a: &Job, b: &Job) -> bool {
+    (a.0 <= b.0 && a.1 >= b.0) || (a.0 <= b.1 && a.1 >= b.1) || (a.0 <= b.1 && a.1 >= b.0)
+}
+
+fn parse_jobs(input: &str) -> Result<Vec<ElfPair>, ParseError> {
+    Ok(parse_lines::<Assignment>(input)?
+        .into_iter()
+        .map(|a| ((a.a_lo, a.a_hi), (a.b_lo, a.b_hi)))
+        .collect())
+}
+
+pub struct Day004 {
+    jobs: Vec<ElfPair>,
+}
+
+impl Solution for Day004 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day004 {
+            jobs: parse_jobs(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.jobs
+            .iter()
+            .map(|j| contained_in(&j.0, &j.1) || contained_in(&j.1, &j.0))
+            .filter(|b| *b)
+            .count()
+    }
+
+    fn part2(&self) -> usize {
+        self.jobs
+            .iter()
+            .map(|j| overlapping(&j.0, &j.1))
+            .filter(|b| *b)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"#;
+
+    #[test]
+    fn test_parse() {
+        let jobs = parse_jobs(SAMPLE).unwrap();
+        assert_eq!(jobs.len(), 6);
+        assert_eq!(jobs[0].0 .0, 2);
+        assert_eq!(jobs[0].0 .1, 4);
+        assert_eq!(jobs[0].1 .0, 6);
+        assert_eq!(jobs[0].1 .1, 8);
+        assert_eq!(jobs[4].0 .0, 6);
+        assert_eq!(jobs[4].0 .1, 6);
+        assert_eq!(jobs[4].1 .0, 4);
+        assert_eq!(jobs[4].1 .1, 6);
+    }
+
+    #[test]
+    fn test_contains() {
+        let jobs = parse_jobs(SAMPLE).unwrap();
+        assert!(!contained_in(&jobs[0].1, &jobs[0].0));
+        assert!(!contained_in(&jobs[3].0, &jobs[3].1));
+        assert!(contained_in(&jobs[3].1, &jobs[3].0));
+    }
+
+    #[test]
+    fn test_count_contains() {
+        let jobs = parse_jobs(SAMPLE).unwrap();
+        let count = jobs
+            .iter()
+            .map(|j| contained_in(&j.0, &j.1) || contained_in(&j.1, &j.0))
+            .filter(|b| *b)
+            .count();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let jobs = parse_jobs(SAMPLE).unwrap();
+        assert!(!overlapping(&jobs[0].1, &jobs[0].0));
+        assert!(!overlapping(&jobs[1].0, &jobs[1].1));
+        assert!(overlapping(&jobs[2].1, &jobs[2].0));
+        assert!(overlapping(&jobs[3].1, &jobs[3].0));
+        assert!(overlapping(&jobs[4].1, &jobs[4].0));
+        assert!(overlapping(&jobs[5].1, &jobs[5].0));
+    }
+
+    #[test]
+    fn test_count_overlaps() {
+        let jobs = parse_jobs(SAMPLE).unwrap();
+        let count = jobs
+            .iter()
+            .map(|j| overlapping(&j.0, &j.1))
+            .filter(|b| *b)
+            .count();
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_pattern_match() {
+        assert!("5-7,6-9".parse::<Assignment>().is_ok());
+        assert!("522-794,62302-954322".parse::<Assignment>().is_ok());
+        let e = parse_jobs("5-7,6-9\n5-7;6-9").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.reason.as_str()),
+            (2, 3, "expected `,`")
+        );
+    }
+
+    #[test]
+    fn test_pattern_fields() {
+        let a: Assignment = "54-78,62-91".parse().unwrap();
+        assert_eq!((a.a_lo, a.a_hi, a.b_lo, a.b_hi), (54, 78, 62, 91));
+    }
+}
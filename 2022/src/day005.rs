@@ -1,5 +1,5 @@
+use aoc::{ParseError, Solution};
 use regex::Regex;
-use std::{fs::File, io::Read};
 
 type Crate = Vec::<char>;
 struct Move {
@@ -68,7 +68,7 @@ fn parse_crates_and_moves(input: &str) -> (Vec<Crate>, Vec<Move>) {
     (crates, moves)
 }
 
-fn execute_9000(moves: &Vec<Move>, crates: &mut [Crate]) {
+fn execute_9000(moves: &[Move], crates: &mut [Crate]) {
     for m in moves {
         for _ in 0..m.n {
             let c = crates[m.from].pop().unwrap();
@@ -77,7 +77,7 @@ fn execute_9000(moves: &Vec<Move>, crates: &mut [Crate]) {
     }
 }
 
-fn execute_9001(moves: &Vec<Move>, crates: &mut [Crate]) {
+fn execute_9001(moves: &[Move], crates: &mut [Crate]) {
     for m in moves {
         let n = crates[m.from].len();
         let cargo = crates[m.from].split_off(n - m.n);
@@ -87,34 +87,44 @@ fn execute_9001(moves: &Vec<Move>, crates: &mut [Crate]) {
     }
 }
 
-fn main() {
-    let mut f = File::open("input/005.txt").expect("File Error");
-    let mut input = String::new();
-    f.read_to_string(&mut input).expect("File Read Error");
-
-    let (mut crates, moves) = parse_crates_and_moves(&input);
-    println!("moves: {:?}", moves.len());
-
-    let mut saved_crates = crates.clone();
-
-    execute_9000(&moves, &mut crates);
+fn top_of_stacks(crates: &[Crate]) -> String {
     let mut output = String::new();
     for stack in crates {
         output.push(*stack.last().unwrap());
     }
-    println!("9000 top of stacks: {output}");
+    output
+}
 
-    execute_9001(&moves, &mut saved_crates);
-    let mut output = String::new();
-    for stack in saved_crates {
-        output.push(*stack.last().unwrap());
+pub struct Day005 {
+    crates: Vec<Crate>,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day005 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (crates, moves) = parse_crates_and_moves(input);
+        Ok(Day005 { crates, moves })
+    }
+
+    fn part1(&self) -> String {
+        let mut crates = self.crates.clone();
+        execute_9000(&self.moves, &mut crates);
+        top_of_stacks(&crates)
+    }
+
+    fn part2(&self) -> String {
+        let mut crates = self.crates.clone();
+        execute_9001(&self.moves, &mut crates);
+        top_of_stacks(&crates)
     }
-    println!("9001 top of stacks: {output}");
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"    [D]    
 [N] [C]    
 [Z] [M] [P]
@@ -1,5 +1,5 @@
+use aoc::{ParseError, Solution};
 use std::collections::{HashSet, VecDeque};
-use std::{fs::File, io::Read};
 
 fn all_different(v: &VecDeque<char>) -> bool {
     let mut hash = HashSet::with_capacity(v.len());
@@ -34,18 +34,32 @@ fn find_message(input: &str) -> usize {
     find_marker(input, 14)
 }
 
-fn main() {
-    let mut f = File::open("input/006.txt").expect("File Error");
-    let mut input = String::new();
-    f.read_to_string(&mut input).expect("File Read Error");
+pub struct Day006 {
+    signal: String,
+}
+
+impl Solution for Day006 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day006 {
+            signal: input.trim().to_string(),
+        })
+    }
 
-    println!("first packet: {}", find_packet(&input));
-    println!("first message: {}", find_message(&input));
+    fn part1(&self) -> usize {
+        find_packet(&self.signal)
+    }
+
+    fn part2(&self) -> usize {
+        find_message(&self.signal)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_find_packet() {
@@ -0,0 +1,877 @@
+use aoc::{debug, trace, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NodeFlavor {
+    Dir,
+    File,
+}
+use NodeFlavor::{Dir, File};
+
+/// Where a node lives in the `Filesystem`'s arena.
+type Inode = usize;
+
+/// The root directory, which is its own parent so `cd ..` can't leave it.
+const ROOT: Inode = 0;
+
+/// How big the device's disk is, and how much of it the update needs free.
+const DISK: usize = 70000000;
+const NEEDED: usize = 30000000;
+
+#[derive(Clone, Debug)]
+struct Node {
+    flavor: NodeFlavor,
+    name: String,
+    parent: Inode,
+    /// A file's own size, or everything below a directory added up, kept
+    /// current as the tree grows.
+    size: usize,
+    /// In the order `ls` listed them.
+    children: Vec<Inode>,
+}
+
+/// A directory tree, every node in one arena and linked by index, with the
+/// size of each directory worked out as its files are found.
+#[derive(Clone, Debug)]
+struct Filesystem {
+    nodes: Vec<Node>,
+}
+
+impl Filesystem {
+    fn new() -> Self {
+        Filesystem {
+            nodes: vec![Node {
+                flavor: Dir,
+                name: String::from("/"),
+                parent: ROOT,
+                size: 0,
+                children: Vec::new(),
+            }],
+        }
+    }
+
+    /// How many files and directories are still in the tree.
+    fn len(&self) -> usize {
+        self.walk(ROOT).len()
+    }
+
+    fn node(&self, inode: Inode) -> &Node {
+        &self.nodes[inode]
+    }
+
+    fn size(&self, inode: Inode) -> usize {
+        self.nodes[inode].size
+    }
+
+    fn child(&self, dir: Inode, name: &str) -> Option<Inode> {
+        self.nodes[dir]
+            .children
+            .iter()
+            .copied()
+            .find(|&c| self.nodes[c].name == name)
+    }
+
+    /// Follows `path` from `cwd`, the way `cd` would: from the root when it
+    /// starts with `/`, with `..` going up and `.` staying put.
+    fn resolve(&self, cwd: Inode, path: &str) -> Option<Inode> {
+        let start = if path.starts_with('/') { ROOT } else { cwd };
+        path.split('/')
+            .filter(|step| !step.is_empty())
+            .try_fold(start, |here, step| match step {
+                "." => Some(here),
+                ".." => Some(self.nodes[here].parent),
+                name => self.child(here, name),
+            })
+    }
+
+    /// Puts `name` in `dir`, unless it's already there from an earlier `ls`,
+    /// and adds its size to every directory above it.
+    fn add(&mut self, dir: Inode, flavor: NodeFlavor, name: &str, size: usize) -> Inode {
+        if let Some(seen) = self.child(dir, name) {
+            return seen;
+        }
+        let inode = self.nodes.len();
+        self.nodes.push(Node {
+            flavor,
+            name: name.to_string(),
+            parent: dir,
+            size: 0,
+            children: Vec::new(),
+        });
+        self.nodes[dir].children.push(inode);
+        self.grow(inode, size);
+        inode
+    }
+
+    /// `inode` and every directory above it, up to the root.
+    fn ancestors(&self, mut inode: Inode) -> Vec<Inode> {
+        let mut up = vec![inode];
+        while inode != ROOT {
+            inode = self.nodes[inode].parent;
+            up.push(inode);
+        }
+        up
+    }
+
+    /// Adds `by` to the size of `inode` and of everything above it.
+    fn grow(&mut self, inode: Inode, by: usize) {
+        for i in self.ancestors(inode) {
+            self.nodes[i].size += by;
+        }
+    }
+
+    /// Takes `inode` and everything below it out of the tree, and its size
+    /// off every directory above it. Its nodes stay in the arena, but with
+    /// nothing pointing at them they're never reached again.
+    fn remove(&mut self, inode: Inode) {
+        assert_ne!(inode, ROOT, "the root can't be removed");
+        let parent = self.nodes[inode].parent;
+        self.nodes[parent].children.retain(|&c| c != inode);
+        let size = self.nodes[inode].size;
+        for i in self.ancestors(parent) {
+            self.nodes[i].size -= size;
+        }
+    }
+
+    /// `inode` and everything below it, each directory before what's in it.
+    fn walk(&self, inode: Inode) -> Vec<Inode> {
+        let mut found = vec![inode];
+        for &c in &self.nodes[inode].children {
+            found.extend(self.walk(c));
+        }
+        found
+    }
+
+    fn path(&self, inode: Inode) -> String {
+        if inode == ROOT {
+            return String::from("/");
+        }
+        let parent = self.nodes[inode].parent;
+        let up = if parent == ROOT {
+            String::new()
+        } else {
+            self.path(parent)
+        };
+        format!("{up}/{}", self.nodes[inode].name)
+    }
+
+    fn dirs(&self) -> impl Iterator<Item = &Node> {
+        self.walk(ROOT)
+            .into_iter()
+            .map(|i| &self.nodes[i])
+            .filter(|n| n.flavor == Dir)
+    }
+
+    /// How much more has to go before there's room for the update, which
+    /// includes whatever a log that overfills the disk has gone over by.
+    fn to_free(&self) -> usize {
+        (self.size(ROOT) + NEEDED).saturating_sub(DISK)
+    }
+
+    /// Every directory from `inode` down with its size, the deepest first,
+    /// the way `du` lists them.
+    fn du(&self, inode: Inode) -> String {
+        let mut report = String::new();
+        for &c in &self.nodes[inode].children {
+            if self.nodes[c].flavor == Dir {
+                report += &self.du(c);
+            }
+        }
+        report + &format!("{}\t{}\n", self.size(inode), self.path(inode))
+    }
+
+    /// Everything from `inode` down, drawn the way the puzzle draws it but
+    /// with the size of every directory too.
+    fn tree(&self, inode: Inode) -> String {
+        let mut report = String::new();
+        self.draw(inode, 0, &mut report);
+        report
+    }
+
+    fn draw(&self, inode: Inode, depth: usize, report: &mut String) {
+        let node = &self.nodes[inode];
+        let flavor = match node.flavor {
+            Dir => "dir",
+            File => "file",
+        };
+        let indent = "  ".repeat(depth);
+        *report += &format!("{indent}- {} ({flavor}, size={})\n", node.name, node.size);
+        for &c in &node.children {
+            self.draw(c, depth + 1, report);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Log {
+    CD(String),
+    LS,
+    Dir(String),
+    File(String, usize),
+}
+
+lazy_static! {
+    static ref CD_RE: regex::Regex = Regex::new(r"^\$ cd ([/\w.-]+)$").unwrap();
+    static ref LS_RE: regex::Regex = Regex::new(r"^\$ ls$").unwrap();
+    static ref DIR_RE: regex::Regex = Regex::new(r"^dir ([\w.-]+)$").unwrap();
+    static ref FILE_RE: regex::Regex = Regex::new(r"^([0-9]+) ([\w.-]+)$").unwrap();
+}
+impl FromStr for Log {
+    type Err = ParseError;
+
+    fn from_str(log: &str) -> Result<Self, ParseError> {
+        if LS_RE.is_match(log) {
+            Ok(Log::LS)
+        } else if let Some(cap) = CD_RE.captures(log) {
+            Ok(Log::CD(cap[1].to_string()))
+        } else if let Some(cap) = DIR_RE.captures(log) {
+            Ok(Log::Dir(cap[1].to_string()))
+        } else if let Some(cap) = FILE_RE.captures(log) {
+            let size = cap.get(1).unwrap().as_str();
+            let size = size
+                .parse()
+                .map_err(|_| ParseError::new("file size out of range", log, size))?;
+            Ok(Log::File(cap[2].to_string(), size))
+        } else if let Some(command) = log.strip_prefix("$ ") {
+            Err(ParseError::new("unrecognized command", log, command))
+        } else {
+            Err(ParseError::line("unrecognized log line", log))
+        }
+    }
+}
+
+fn parse_logs(input: &str) -> Result<Filesystem, ParseError> {
+    let mut fs = Filesystem::new();
+    // nowhere until the first cd
+    let mut cwd = None;
+    for (i, line) in input.lines().enumerate() {
+        let lost = || ParseError::line("listing outside any directory", line).below(i);
+        match line.parse().map_err(|e: ParseError| e.below(i))? {
+            Log::CD(path) => {
+                let found = match cwd {
+                    Some(here) => fs.resolve(here, &path),
+                    None if path.starts_with('/') => fs.resolve(ROOT, &path),
+                    None => None,
+                };
+                match found {
+                    Some(dir) if fs.node(dir).flavor == Dir => cwd = Some(dir),
+                    _ => {
+                        let path = &line[line.len() - path.len()..];
+                        return Err(ParseError::new("no such directory", line, path).below(i));
+                    }
+                }
+            }
+            Log::LS => (),
+            Log::File(name, size) => {
+                fs.add(cwd.ok_or_else(lost)?, File, &name, size);
+            }
+            Log::Dir(name) => {
+                fs.add(cwd.ok_or_else(lost)?, Dir, &name, 0);
+            }
+        }
+    }
+    Ok(fs)
+}
+
+fn part_1(fs: &Filesystem) -> usize {
+    fs.dirs()
+        .map(|d| d.size)
+        .filter(|&size| size <= 100000)
+        .sum()
+}
+
+fn part_2(fs: &Filesystem) -> usize {
+    let goal = fs.to_free();
+    assert!(goal > 0);
+    trace!("to free {goal} out of\n{}", fs.du(ROOT));
+    fs.dirs()
+        .map(|d| d.size)
+        .filter(|&size| size > goal)
+        .min()
+        .expect("no candidates found")
+}
+
+/// What the shell understands, each a little like the real thing.
+#[derive(Debug, PartialEq)]
+enum Command {
+    Cd(String),
+    Ls(String),
+    Pwd,
+    Du {
+        path: String,
+        summary: bool,
+    },
+    Tree(String),
+    Df,
+    Find {
+        path: String,
+        /// How a size has to compare with the given one, from `-size +N`,
+        /// `-size -N` or `-size N`.
+        size: Option<(Ordering, usize)>,
+        flavor: Option<NodeFlavor>,
+    },
+    Rm {
+        path: String,
+        recursive: bool,
+    },
+}
+
+/// The path a command was given, or where the shell is.
+fn operand(name: &str, args: &[&str]) -> Result<String, String> {
+    match args {
+        [] => Ok(String::from(".")),
+        [path] => Ok(path.to_string()),
+        _ => Err(format!("{name}: too many arguments")),
+    }
+}
+
+fn parse_find(args: &[&str]) -> Result<Command, String> {
+    let (path, mut tests) = match args {
+        [path, tests @ ..] if !path.starts_with('-') => (path.to_string(), tests),
+        _ => (String::from("."), args),
+    };
+    let (mut size, mut flavor) = (None, None);
+    while let [test, value, rest @ ..] = tests {
+        match *test {
+            "-size" => {
+                let (order, n) = match (value.strip_prefix('+'), value.strip_prefix('-')) {
+                    (Some(n), _) => (Ordering::Greater, n),
+                    (_, Some(n)) => (Ordering::Less, n),
+                    _ => (Ordering::Equal, *value),
+                };
+                let n = n.parse().map_err(|_| format!("find: bad size {value}"))?;
+                size = Some((order, n));
+            }
+            "-type" => {
+                flavor = Some(match *value {
+                    "d" => Dir,
+                    "f" => File,
+                    _ => return Err(format!("find: unknown type {value}")),
+                })
+            }
+            _ => return Err(format!("find: unknown test {test}")),
+        }
+        tests = rest;
+    }
+    if let [test] = tests {
+        return Err(format!("find: {test} needs a value"));
+    }
+    Ok(Command::Find { path, size, flavor })
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            return Err(String::from("nothing to run"));
+        };
+        match (name, args) {
+            ("cd", []) => Ok(Command::Cd(String::from("/"))),
+            ("cd", args) => Ok(Command::Cd(operand(name, args)?)),
+            ("ls", args) => Ok(Command::Ls(operand(name, args)?)),
+            ("pwd", []) => Ok(Command::Pwd),
+            ("du", ["-s", args @ ..]) => Ok(Command::Du {
+                path: operand(name, args)?,
+                summary: true,
+            }),
+            ("du", args) => Ok(Command::Du {
+                path: operand(name, args)?,
+                summary: false,
+            }),
+            ("tree", args) => Ok(Command::Tree(operand(name, args)?)),
+            ("df", []) => Ok(Command::Df),
+            ("find", args) => parse_find(args),
+            ("rm", ["-r", path]) => Ok(Command::Rm {
+                path: path.to_string(),
+                recursive: true,
+            }),
+            ("rm", [path]) if !path.starts_with('-') => Ok(Command::Rm {
+                path: path.to_string(),
+                recursive: false,
+            }),
+            ("rm", _) => Err(String::from("usage: rm [-r] PATH")),
+            ("pwd" | "df", _) => Err(format!("{name}: too many arguments")),
+            _ => Err(format!("{name}: command not found")),
+        }
+    }
+}
+
+/// The filesystem a day 7 log describes, to poke around in with a few shell
+/// commands: `cd`, `ls`, `pwd`, `du [-s]`, `tree`, `df`,
+/// `find [PATH] [-type d|f] [-size +N|-N|N]` and `rm [-r]`. Removing things
+/// frees up their space, so part 2's choice of what to delete can be tried
+/// out rather than just worked out.
+pub struct Shell {
+    fs: Filesystem,
+    cwd: Inode,
+}
+
+impl Shell {
+    pub fn new(log: &str) -> Result<Self, ParseError> {
+        Ok(Shell {
+            fs: parse_logs(log)?,
+            cwd: ROOT,
+        })
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("{} $ ", self.fs.path(self.cwd))
+    }
+
+    /// Runs one command line, and says what it printed or what was wrong.
+    pub fn run(&mut self, line: &str) -> Result<String, String> {
+        match line.parse()? {
+            Command::Cd(path) => {
+                let dir = self.lookup(&path)?;
+                if self.fs.node(dir).flavor != Dir {
+                    return Err(format!("cd: {path}: not a directory"));
+                }
+                self.cwd = dir;
+                Ok(String::new())
+            }
+            Command::Ls(path) => {
+                let inode = self.lookup(&path)?;
+                let node = self.fs.node(inode);
+                let entries = match node.flavor {
+                    Dir => node.children.clone(),
+                    File => vec![inode],
+                };
+                Ok(entries.into_iter().map(|i| self.listing(i)).collect())
+            }
+            Command::Pwd => Ok(format!("{}\n", self.fs.path(self.cwd))),
+            Command::Du { path, summary } => {
+                let inode = self.lookup(&path)?;
+                Ok(match summary {
+                    true => format!("{}\t{}\n", self.fs.size(inode), self.fs.path(inode)),
+                    false => self.fs.du(inode),
+                })
+            }
+            Command::Tree(path) => Ok(self.fs.tree(self.lookup(&path)?)),
+            Command::Df => {
+                let used = self.fs.size(ROOT);
+                let free = match DISK.checked_sub(used) {
+                    Some(free) => format!("free {free}"),
+                    None => format!("over capacity by {}", used - DISK),
+                };
+                Ok(format!(
+                    "size {DISK}, used {used}, {free}, still to free {}\n",
+                    self.fs.to_free()
+                ))
+            }
+            Command::Find { path, size, flavor } => {
+                let found = self.fs.walk(self.lookup(&path)?).into_iter().filter(|&i| {
+                    let node = self.fs.node(i);
+                    size.is_none_or(|(order, n)| node.size.cmp(&n) == order)
+                        && flavor.is_none_or(|f| node.flavor == f)
+                });
+                Ok(found.map(|i| self.fs.path(i) + "\n").collect())
+            }
+            Command::Rm { path, recursive } => {
+                let inode = self.lookup(&path)?;
+                if inode == ROOT {
+                    return Err(String::from("rm: refusing to remove /"));
+                }
+                if self.fs.node(inode).flavor == Dir && !recursive {
+                    return Err(format!("rm: {path}: is a directory"));
+                }
+                // don't leave the shell standing somewhere that's gone
+                if self.fs.ancestors(self.cwd).contains(&inode) {
+                    self.cwd = self.fs.node(inode).parent;
+                }
+                self.fs.remove(inode);
+                Ok(String::new())
+            }
+        }
+    }
+
+    fn lookup(&self, path: &str) -> Result<Inode, String> {
+        self.fs
+            .resolve(self.cwd, path)
+            .ok_or_else(|| format!("{path}: no such file or directory"))
+    }
+
+    /// A line of `ls`, written the way the log writes them.
+    fn listing(&self, inode: Inode) -> String {
+        let node = self.fs.node(inode);
+        match node.flavor {
+            Dir => format!("dir {}\n", node.name),
+            File => format!("{} {}\n", node.size, node.name),
+        }
+    }
+}
+
+pub struct Day007 {
+    fs: Filesystem,
+}
+
+impl Solution for Day007 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let fs = parse_logs(input)?;
+        debug!(
+            "rebuilt {} files and directories, {} used",
+            fs.len(),
+            fs.size(ROOT)
+        );
+        trace!("\n{}", fs.tree(ROOT));
+        Ok(Day007 { fs })
+    }
+
+    fn part1(&self) -> usize {
+        part_1(&self.fs)
+    }
+
+    fn part2(&self) -> usize {
+        part_2(&self.fs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"#;
+    // - / (dir)
+    //   - a (dir)
+    //     - e (dir)
+    //       - i (file, size=584)
+    //     - f (file, size=29116)
+    //     - g (file, size=2557)
+    //     - h.lst (file, size=62596)
+    //   - b.txt (file, size=14848514)
+    //   - c.dat (file, size=8504156)
+    //   - d (dir)
+    //     - j (file, size=4060174)
+    //     - d.log (file, size=8033020)
+    //     - d.ext (file, size=5626152)
+    //     - k (file, size=7214296)
+    #[test]
+    fn test_parse_log() {
+        let filesystem = parse_logs(SAMPLE).unwrap();
+        assert_eq!(filesystem.len(), 14);
+        for path in ["/a", "/b.txt", "/c.dat", "/a/e", "/a/e/i"] {
+            let inode = filesystem.resolve(ROOT, path).unwrap();
+            assert_eq!(filesystem.path(inode), path);
+        }
+        let i = filesystem.resolve(ROOT, "/a/e/i").unwrap();
+        assert_eq!(filesystem.node(i).flavor, File);
+    }
+
+    #[test]
+    fn test_size() {
+        let filesystem = parse_logs(SAMPLE).unwrap();
+        let size = |path| filesystem.size(filesystem.resolve(ROOT, path).unwrap());
+        assert_eq!(size("/a/e"), 584);
+        assert_eq!(size("/a"), 94853);
+        assert_eq!(size("/d"), 24933642);
+        assert_eq!(size("/"), 48381165);
+        assert_eq!(size("/d/d.log"), 8033020);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let fs = parse_logs(SAMPLE).unwrap();
+        let e = fs.resolve(ROOT, "/a/e").unwrap();
+        assert_eq!(fs.resolve(e, ".."), fs.resolve(ROOT, "a"));
+        assert_eq!(fs.resolve(e, "../../d/./j"), fs.resolve(ROOT, "/d/j"));
+        assert_eq!(fs.resolve(e, "/"), Some(ROOT));
+        assert_eq!(fs.resolve(ROOT, ".."), Some(ROOT));
+        assert_eq!(fs.resolve(e, "d"), None);
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.path(ROOT), "/");
+    }
+
+    #[test]
+    fn test_wandering_log() {
+        // absolute paths, going back to the root, names with - and _, and
+        // listing the same directories twice
+        let log = r#"$ cd /
+$ ls
+dir my-dir
+10 top_file
+$ cd my-dir
+$ ls
+dir sub_1
+$ cd /my-dir/sub_1
+$ ls
+200 deep-file.txt
+$ cd /
+$ ls
+dir my-dir
+10 top_file
+$ cd my-dir/sub_1/../sub_1
+$ ls
+200 deep-file.txt"#;
+        let fs = parse_logs(log).unwrap();
+        assert_eq!(fs.len(), 5);
+        assert_eq!(fs.size(ROOT), 210);
+        let sub = fs.resolve(ROOT, "/my-dir/sub_1").unwrap();
+        assert_eq!(fs.size(sub), 200);
+        assert_eq!(fs.path(sub), "/my-dir/sub_1");
+        let e = parse_logs("$ cd /\n$ ls\n10 f\n$ cd f").unwrap_err();
+        assert_eq!((e.line, e.reason.as_str()), (4, "no such directory"));
+    }
+
+    #[test]
+    fn test_du() {
+        let fs = parse_logs(SAMPLE).unwrap();
+        assert_eq!(
+            fs.du(ROOT),
+            "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/\n"
+        );
+        let a = fs.resolve(ROOT, "/a").unwrap();
+        assert_eq!(fs.du(a), "584\t/a/e\n94853\t/a\n");
+    }
+
+    #[test]
+    fn test_tree() {
+        let fs = parse_logs(SAMPLE).unwrap();
+        let a = fs.resolve(ROOT, "/a").unwrap();
+        assert_eq!(
+            fs.tree(a),
+            "- a (dir, size=94853)
+  - e (dir, size=584)
+    - i (file, size=584)
+  - f (file, size=29116)
+  - g (file, size=2557)
+  - h.lst (file, size=62596)
+"
+        );
+        assert_eq!(fs.tree(ROOT).lines().count(), 14);
+    }
+
+    #[test]
+    fn test_cd_regex() {
+        assert!(CD_RE.is_match("$ cd /"));
+        assert!(CD_RE.is_match("$ cd .."));
+        assert!(!CD_RE.is_match("$ ls"));
+        assert!(!CD_RE.is_match("dir a"));
+        assert!(!CD_RE.is_match("14848514 b.txt"));
+        assert!(!CD_RE.is_match("dir def"));
+        assert_eq!(
+            CD_RE.captures("$ cd def").unwrap().get(1).unwrap().as_str(),
+            "def"
+        );
+        assert!(CD_RE.is_match("$ cd /a/e"));
+        assert!(CD_RE.is_match("$ cd my-dir_2"));
+        assert!(!CD_RE.is_match("$ cd a b"));
+    }
+
+    #[test]
+    fn test_ls_regex() {
+        assert!(!LS_RE.is_match("$ cd /"));
+        assert!(LS_RE.is_match("$ ls"));
+        assert!(!LS_RE.is_match("dir a"));
+        assert!(!LS_RE.is_match("14848514 b.txt"));
+    }
+
+    #[test]
+    fn test_dir_regex() {
+        assert!(!DIR_RE.is_match("$ cd /"));
+        assert!(!DIR_RE.is_match("$ ls"));
+        assert!(DIR_RE.is_match("dir a"));
+        assert!(!DIR_RE.is_match("14848514 b.txt"));
+        assert_eq!(
+            DIR_RE.captures("dir abc").unwrap().get(1).unwrap().as_str(),
+            "abc"
+        );
+        assert!(DIR_RE.is_match("dir my-dir_2"));
+        assert!(!DIR_RE.is_match("dir a/b"));
+    }
+
+    #[test]
+    fn test_file_regex() {
+        assert!(!FILE_RE.is_match("$ cd /"));
+        assert!(!FILE_RE.is_match("$ ls"));
+        assert!(!FILE_RE.is_match("dir a"));
+        assert!(FILE_RE.is_match("14848514 b.txt"));
+        assert_eq!(
+            FILE_RE
+                .captures("14848514 b.txt")
+                .unwrap()
+                .get(1)
+                .unwrap()
+                .as_str()
+                .parse::<u32>()
+                .unwrap(),
+            14848514
+        );
+        assert_eq!(
+            FILE_RE
+                .captures("14848514 b.txt")
+                .unwrap()
+                .get(2)
+                .unwrap()
+                .as_str(),
+            "b.txt"
+        );
+    }
+
+    #[test]
+    fn test_parse_log_line() {
+        assert_eq!("$ cd /".parse(), Ok(Log::CD(String::from("/"))));
+        assert_eq!("$ ls".parse(), Ok(Log::LS));
+        assert_eq!("dir a".parse(), Ok(Log::Dir(String::from("a"))));
+        assert_eq!(
+            "14848514 b.txt".parse(),
+            Ok(Log::File(String::from("b.txt"), 14848514))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "$ rm -rf b.txt".parse::<Log>().unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, "rm -rf b.txt"));
+        let e = parse_logs("$ cd /\n$ ls\n14848514 b.txt\nfile c.dat").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+        assert_eq!(e.reason, "unrecognized log line");
+        let e = parse_logs("$ cd /\n$ cd a").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 6, "a"));
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!("cd".parse(), Ok(Command::Cd(String::from("/"))));
+        assert_eq!("ls  /a/e ".parse(), Ok(Command::Ls(String::from("/a/e"))));
+        assert_eq!(
+            "du -s".parse(),
+            Ok(Command::Du {
+                path: String::from("."),
+                summary: true
+            })
+        );
+        assert_eq!(
+            "find / -type d -size +100000".parse(),
+            Ok(Command::Find {
+                path: String::from("/"),
+                size: Some((Ordering::Greater, 100000)),
+                flavor: Some(Dir),
+            })
+        );
+        assert_eq!(
+            "find -size -10".parse(),
+            Ok(Command::Find {
+                path: String::from("."),
+                size: Some((Ordering::Less, 10)),
+                flavor: None,
+            })
+        );
+        assert_eq!(
+            "rm -r d".parse(),
+            Ok(Command::Rm {
+                path: String::from("d"),
+                recursive: true
+            })
+        );
+        assert_eq!(
+            "mkdir x".parse::<Command>(),
+            Err(String::from("mkdir: command not found"))
+        );
+        assert!("find -size".parse::<Command>().is_err());
+        assert!("find -size +big".parse::<Command>().is_err());
+        assert!("find -size é".parse::<Command>().is_err());
+        assert!("rm -f x".parse::<Command>().is_err());
+        assert!("ls a b".parse::<Command>().is_err());
+        assert!("".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_shell() {
+        let mut shell = Shell::new(SAMPLE).unwrap();
+        assert_eq!(shell.prompt(), "/ $ ");
+        assert_eq!(
+            shell.run("ls").unwrap(),
+            "dir a\n14848514 b.txt\n8504156 c.dat\ndir d\n"
+        );
+        assert_eq!(shell.run("cd a/e").unwrap(), "");
+        assert_eq!(shell.prompt(), "/a/e $ ");
+        assert_eq!(shell.run("pwd").unwrap(), "/a/e\n");
+        assert_eq!(shell.run("du -s ..").unwrap(), "94853\t/a\n");
+        assert_eq!(shell.run("ls i").unwrap(), "584 i\n");
+        assert!(shell.run("cd i").is_err());
+        assert!(shell.run("cd nowhere").is_err());
+        assert_eq!(
+            shell.run("find / -type d -size -100001").unwrap(),
+            "/a\n/a/e\n"
+        );
+        assert_eq!(
+            shell.run("find /d -size +8000000").unwrap(),
+            "/d\n/d/d.log\n"
+        );
+        assert_eq!(
+            shell.run("df").unwrap(),
+            "size 70000000, used 48381165, free 21618835, still to free 8381165\n"
+        );
+    }
+
+    #[test]
+    fn test_over_capacity() {
+        let log = "$ cd /\n$ ls\ndir a\n75000000 big.iso\n$ cd a\n$ ls\n1000 b.txt\n";
+        let mut shell = Shell::new(log).unwrap();
+        assert_eq!(
+            shell.run("df").unwrap(),
+            "size 70000000, used 75001000, over capacity by 5001000, still to free 35001000\n"
+        );
+        let day = Day007::parse(log).unwrap();
+        assert_eq!(day.part2(), 75001000);
+    }
+
+    #[test]
+    fn test_shell_rm() {
+        let mut shell = Shell::new(SAMPLE).unwrap();
+        shell.run("cd /a/e").unwrap();
+        assert_eq!(shell.run("rm /a").unwrap_err(), "rm: /a: is a directory");
+        assert!(shell.run("rm -r /").is_err());
+        shell.run("rm -r /a").unwrap();
+        // the shell was in there, so it's put back where it can stand
+        assert_eq!(shell.prompt(), "/ $ ");
+        assert!(shell.run("ls a").is_err());
+        assert_eq!(shell.run("du -s").unwrap(), "48286312\t/\n");
+        shell.run("rm d/k").unwrap();
+        assert_eq!(shell.run("du -s d").unwrap(), "17719346\t/d\n");
+        assert_eq!(shell.run("du").unwrap(), "17719346\t/d\n41072016\t/\n");
+        assert_eq!(shell.fs.len(), 7);
+        assert_eq!(part_1(&shell.fs), 0);
+        assert_eq!(part_2(&shell.fs), 17719346);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let fs = parse_logs(SAMPLE).unwrap();
+        assert_eq!(part_1(&fs), 95437);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let fs = parse_logs(SAMPLE).unwrap();
+        assert_eq!(part_2(&fs), 24933642);
+    }
+}
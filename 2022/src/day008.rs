@@ -0,0 +1,118 @@
+use aoc::{Grid, ParseError, Pos, Solution};
+
+fn parse_trees(input: &str) -> Result<Grid<i8>, ParseError> {
+    Grid::try_parse(input, |c| c.to_digit(10).map(|d| d as i8))
+}
+
+fn sweep(orchard: &Grid<i8>, seen: &mut Grid<bool>, line: impl Iterator<Item = Pos>) {
+    let mut max = -1;
+    for p in line {
+        if orchard[p] > max {
+            seen[p] = true;
+            max = orchard[p];
+        }
+    }
+}
+
+fn visible(orchard: &Grid<i8>) -> usize {
+    let (w, h) = (orchard.width(), orchard.height());
+    let mut seen = Grid::new(w, h, false);
+    for y in 0..h {
+        sweep(orchard, &mut seen, (0..w).map(|x| (x, y)));
+        sweep(orchard, &mut seen, (0..w).rev().map(|x| (x, y)));
+    }
+    for x in 0..w {
+        sweep(orchard, &mut seen, (0..h).map(|y| (x, y)));
+        sweep(orchard, &mut seen, (0..h).rev().map(|y| (x, y)));
+    }
+    seen.iter().filter(|s| **s).count()
+}
+
+fn score_location(orchard: &Grid<i8>, y: usize, x: usize) -> u32 {
+    let tree = orchard[(x, y)];
+    [(1, 0), (-1, 0), (0, 1), (0, -1)]
+        .iter()
+        .map(|&(dx, dy)| {
+            let mut score = 0;
+            let (mut i, mut j) = (x as isize + dx, y as isize + dy);
+            while let Some(&other) = orchard.get(i, j) {
+                score += 1;
+                if tree <= other {
+                    break;
+                }
+                i += dx;
+                j += dy;
+            }
+            score
+        })
+        .product()
+}
+
+fn best_view(orchard: &Grid<i8>) -> u32 {
+    orchard
+        .positions()
+        .map(|(x, y)| score_location(orchard, y, x))
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day008 {
+    orchard: Grid<i8>,
+}
+
+impl Solution for Day008 {
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day008 {
+            orchard: parse_trees(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        visible(&self.orchard)
+    }
+
+    fn part2(&self) -> u32 {
+        best_view(&self.orchard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"30373
+25512
+65332
+33549
+35390"#;
+
+    #[test]
+    fn test_parse_log() {
+        let orchard = parse_trees(SAMPLE).unwrap();
+        assert_eq!(orchard[(0, 0)], 3);
+        assert_eq!(orchard[(2, 3)], 5);
+        let e = parse_trees("123\n4x6\n789").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_visibile() {
+        let visibile = visible(&parse_trees(SAMPLE).unwrap());
+        assert_eq!(visibile, 21);
+    }
+
+    #[test]
+    fn test_score_location() {
+        let orchard = parse_trees(SAMPLE).unwrap();
+        assert_eq!(score_location(&orchard, 1, 2), 4);
+        assert_eq!(score_location(&orchard, 3, 2), 8);
+    }
+
+    #[test]
+    fn test_best_location() {
+        let orchard = parse_trees(SAMPLE).unwrap();
+        assert_eq!(best_view(&orchard), 8);
+    }
+}
@@ -0,0 +1,184 @@
+use aoc::{parse_lines, Direction, ParseError, Point2, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+use Direction::{East, North, South, West};
+
+struct Op {
+    d: Direction,
+    n: i32,
+}
+type Position = Point2<i32>;
+type State = Vec<Position>; // head, tail
+type Program = Vec<Op>;
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let (d, n) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::line("move has no distance", line))?;
+        let d = match d {
+            "U" => North,
+            "D" => South,
+            "R" => East,
+            "L" => West,
+            _ => return Err(ParseError::new("unrecognized direction", line, d)),
+        };
+        let n = n
+            .parse()
+            .map_err(|_| ParseError::new("distance is not a number", line, n))?;
+        Ok(Op { d, n })
+    }
+}
+
+fn execute(s: &State, d: &Direction) -> State {
+    let mut s = s.clone();
+    s[0] += d.offset();
+    s
+}
+
+fn follow(s: &State) -> State {
+    let mut next = State::new();
+    let mut prev = None;
+    for t in s {
+        match prev {
+            None => {
+                prev = Some(*t);
+                next.push(*t);
+            }
+            Some(h) => {
+                let mut t = *t;
+                if h.chebyshev(&t) == 2 {
+                    t += (h - t).signum();
+                }
+                prev = Some(t);
+                next.push(t);
+            }
+        }
+    }
+    next
+}
+
+fn run_program(moves: &Program, num_knots: usize) -> usize {
+    let mut trace = HashSet::new();
+    let mut s = Vec::new();
+    s.resize_with(num_knots + 1, Position::default);
+    trace.insert(s[num_knots]);
+    for op in moves {
+        for _ in 0..op.n {
+            s = follow(&execute(&s, &op.d));
+            trace.insert(s[num_knots]);
+        }
+    }
+    trace.len()
+}
+
+pub struct Day009 {
+    program: Program,
+}
+
+impl Solution for Day009 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day009 {
+            program: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        run_program(&self.program, 1)
+    }
+
+    fn part2(&self) -> usize {
+        run_program(&self.program, 9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn knots(positions: &[(i32, i32)]) -> State {
+        positions.iter().map(|&p| Position::from(p)).collect()
+    }
+
+    const SAMPLE: &str = r#"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2"#;
+    const LONG_SAMPLE: &str = r#"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20"#;
+    #[test]
+    fn test_parse_moves() {
+        let program: Program = parse_lines(SAMPLE).unwrap();
+        assert_eq!(program.len(), 8);
+        let e = parse_lines::<Op>("R 4\nQ 5").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "Q"));
+        let e = parse_lines::<Op>("R 4\nU five").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "five"));
+        assert!(parse_lines::<Op>("R").is_err());
+    }
+
+    #[test]
+    fn test_move() {
+        let s = knots(&[(3, 4), (2, 1)]);
+        // up is north, which is y - 1
+        assert_eq!(execute(&s, &North), knots(&[(3, 3), (2, 1)]));
+        assert_eq!(execute(&s, &South), knots(&[(3, 5), (2, 1)]));
+        assert_eq!(execute(&s, &East), knots(&[(4, 4), (2, 1)]));
+        assert_eq!(execute(&s, &West), knots(&[(2, 4), (2, 1)]));
+        let s = knots(&[(0, 4), (2, 1)]);
+        assert_eq!(execute(&s, &West), knots(&[(-1, 4), (2, 1)]));
+    }
+
+    #[test]
+    fn test_snake_follow() {
+        let mut s = knots(&[(3, 4); 10]);
+        for _ in 0..9 {
+            s = follow(&execute(&s, &East));
+        }
+        assert_eq!(s[0], Position::new(3 + 9, 4));
+        assert_eq!(s[1], Position::new(3 + 8, 4));
+        assert_eq!(s[5], Position::new(3 + 4, 4));
+        assert_eq!(s[9], Position::new(3, 4));
+    }
+
+    #[test]
+    fn test_follow() {
+        assert_eq!(follow(&knots(&[(3, 4), (3, 4)])), knots(&[(3, 4), (3, 4)]));
+        assert_eq!(follow(&knots(&[(3, 4), (3, 5)])), knots(&[(3, 4), (3, 5)]));
+        assert_eq!(follow(&knots(&[(3, 4), (4, 5)])), knots(&[(3, 4), (4, 5)]));
+        assert_eq!(follow(&knots(&[(3, 4), (2, 3)])), knots(&[(3, 4), (2, 3)]));
+
+        assert_eq!(follow(&knots(&[(3, 4), (5, 6)])), knots(&[(3, 4), (4, 5)]));
+        assert_eq!(follow(&knots(&[(3, 4), (1, 2)])), knots(&[(3, 4), (2, 3)]));
+
+        assert_eq!(follow(&knots(&[(3, 4), (4, 6)])), knots(&[(3, 4), (3, 5)]));
+        assert_eq!(follow(&knots(&[(3, 4), (2, 2)])), knots(&[(3, 4), (3, 3)]));
+    }
+
+    #[test]
+    fn test_run_short_tail() {
+        let program: Program = parse_lines(SAMPLE).unwrap();
+        assert_eq!(run_program(&program, 1), 13);
+    }
+
+    #[test]
+    fn test_run_long_tail() {
+        let program: Program = parse_lines(LONG_SAMPLE).unwrap();
+        assert_eq!(run_program(&program, 9), 36);
+    }
+}
@@ -0,0 +1,273 @@
+use aoc::cpu::{Flow, Instruction, Isa, Machine, Peripheral, Registers, Spec, Word};
+use aoc::debugger::Debugger;
+use aoc::{debug, ocr, ParseError, Solution};
+
+/// The handheld's only register.
+const X: usize = 0;
+
+/// How many pixels the CRT draws on a row.
+const WIDTH: usize = 40;
+
+static CPU: Isa = Isa {
+    registers: &[("x", 1)],
+    instructions: &[
+        Spec {
+            name: "noop",
+            operands: 0,
+            cycles: 1,
+            effect: |_, _| Flow::Next,
+        },
+        Spec {
+            name: "addx",
+            operands: 1,
+            cycles: 2,
+            effect: |r, ops| {
+                r.set(X, r.get(X) + r.value(ops[0]));
+                Flow::Next
+            },
+        },
+    ],
+};
+
+type Program = Vec<Instruction>;
+
+/// Adds up the signal strength, the cycle times `x`, during the 20th cycle
+/// and every 40th after that.
+#[derive(Debug, Default)]
+pub struct Sampler {
+    signal: Word,
+}
+
+impl Peripheral for Sampler {
+    fn tick(&mut self, cycle: usize, registers: &Registers) {
+        if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+            self.signal += cycle as Word * registers.get(X);
+        }
+    }
+
+    fn report(&self) -> String {
+        format!("signal: {}\n", self.signal)
+    }
+}
+
+/// The screen, which draws a pixel a cycle along its rows, lit wherever the
+/// three pixel wide sprite centred on `x` covers it.
+#[derive(Debug, Default)]
+pub struct Crt {
+    rows: Vec<String>,
+}
+
+impl Peripheral for Crt {
+    fn tick(&mut self, cycle: usize, registers: &Registers) {
+        let column = (cycle - 1) % WIDTH;
+        if column == 0 {
+            self.rows.push(String::with_capacity(WIDTH));
+        }
+        let lit = (column as Word - registers.get(X)).abs() < 2;
+        let row = self.rows.last_mut().expect("a row was started");
+        row.push(if lit { '#' } else { '.' });
+    }
+
+    fn report(&self) -> String {
+        self.rows.iter().map(|row| format!("{row}\n")).collect()
+    }
+}
+
+fn signal(program: &Program) -> Word {
+    let mut machine = Machine::new(&CPU, program.clone());
+    let mut sampler = Sampler::default();
+    machine.run(&mut sampler);
+    debug!("the program ran for {} cycles", machine.cycle());
+    sampler.signal
+}
+
+fn draw(program: &Program) -> Vec<String> {
+    let mut machine = Machine::new(&CPU, program.clone());
+    let mut crt = Crt::default();
+    machine.run(&mut crt);
+    crt.rows
+}
+
+/// The program in `input` loaded up in the debugger, with both the sampler
+/// and the CRT wired up.
+pub fn debugger(input: &str) -> Result<Debugger<(Sampler, Crt)>, ParseError> {
+    let machine = Machine::new(&CPU, CPU.assemble(input)?);
+    Ok(Debugger::new(machine, Default::default()))
+}
+
+pub struct Day010 {
+    program: Program,
+}
+
+impl Solution for Day010 {
+    type Part1 = Word;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day010 {
+            program: CPU.assemble(input)?,
+        })
+    }
+
+    fn part1(&self) -> Word {
+        signal(&self.program)
+    }
+
+    fn part2(&self) -> String {
+        let rows = draw(&self.program);
+        let mut screen = String::new();
+        for row in &rows {
+            screen.push('\n');
+            screen.push_str(row);
+        }
+        match ocr::read(&rows) {
+            Some(letters) => {
+                debug!("the screen shows{screen}");
+                letters
+            }
+            // the sample draws stripes rather than letters, so there's only
+            // the picture to give back
+            None => screen,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::cpu::{Breakpoint, Status};
+    const SAMPLE: &str = r#"noop
+addx 3
+addx -5"#;
+
+    const LONG_SAMPLE: &str = include_str!("../input/010-sample-1.txt");
+
+    /// Writes down `x` during every cycle.
+    #[derive(Default)]
+    struct Probe(Vec<Word>);
+
+    impl Peripheral for Probe {
+        fn tick(&mut self, _cycle: usize, registers: &Registers) {
+            self.0.push(registers.get(X));
+        }
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        let program = CPU.assemble(SAMPLE).unwrap();
+        assert_eq!(program.len(), 3);
+        assert_eq!(program[0].to_string(), "noop");
+        assert_eq!(program[1].to_string(), "addx 3");
+        assert_eq!(program[2].to_string(), "addx -5");
+        assert_eq!(program[2].spec().cycles, 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = CPU.assemble("noop\naddx 3\naddx five").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 6, "five"));
+        let e = CPU.assemble("noop\nmulx 3").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "mulx"));
+        assert_eq!(
+            CPU.instruction("addx").unwrap_err().reason,
+            "missing operand"
+        );
+    }
+
+    #[test]
+    fn test_tick() {
+        let program = CPU.assemble(SAMPLE).unwrap();
+        let mut cpu = Machine::new(&CPU, program);
+        let mut probe = Probe::default();
+
+        assert_eq!(cpu.step(&mut probe), Status::Running);
+        assert_eq!(cpu.cycle(), 1);
+        assert_eq!(cpu.registers().get(X), 1);
+
+        // addx takes two cycles, and x only changes once both are over
+        cpu.step(&mut probe);
+        assert_eq!((cpu.cycle(), cpu.busy()), (2, 1));
+        assert_eq!(cpu.registers().get(X), 1);
+        cpu.step(&mut probe);
+        assert_eq!((cpu.cycle(), cpu.busy()), (3, 0));
+        assert_eq!(cpu.registers().get(X), 4);
+
+        cpu.step(&mut probe);
+        assert_eq!(cpu.step(&mut probe), Status::Halted);
+        assert_eq!(cpu.cycle(), 5);
+        assert_eq!(cpu.registers().get(X), -1);
+        assert_eq!(probe.0, [1, 1, 1, 4, 4]);
+        assert_eq!(cpu.step(&mut probe), Status::Halted);
+        assert_eq!(cpu.cycle(), 5);
+    }
+
+    #[test]
+    fn test_ticks() {
+        let program = CPU.assemble(LONG_SAMPLE).unwrap();
+        let mut cpu = Machine::new(&CPU, program);
+        for cycle in [20, 60, 100, 140, 180, 220] {
+            cpu.add_breakpoint(Breakpoint::Cycle(cycle));
+        }
+        // stopping before each of those cycles shows x during it
+        for (cycle, x) in [
+            (20, 21),
+            (60, 19),
+            (100, 18),
+            (140, 21),
+            (180, 16),
+            (220, 18),
+        ] {
+            assert_eq!(cpu.run(&mut ()), Status::Break(Breakpoint::Cycle(cycle)));
+            assert_eq!(cpu.registers().get(X), x);
+        }
+        assert_eq!(cpu.run(&mut ()), Status::Halted);
+        assert_eq!(cpu.cycle(), 240);
+    }
+
+    #[test]
+    fn test_signal() {
+        let program = CPU.assemble(LONG_SAMPLE).unwrap();
+        assert_eq!(signal(&program), 13140);
+    }
+
+    #[test]
+    fn test_draw() {
+        let program = CPU.assemble(LONG_SAMPLE).unwrap();
+        assert_eq!(
+            draw(&program),
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let day = Day010::parse(LONG_SAMPLE).unwrap();
+        assert!(day
+            .part2()
+            .starts_with("\n##..##..##..##..##..##..##..##..##..##..\n"));
+    }
+
+    #[test]
+    fn test_debugger() {
+        let mut debugger = debugger(LONG_SAMPLE).unwrap();
+        debugger.run("break cycle 20").unwrap();
+        assert_eq!(
+            debugger.run("continue").unwrap(),
+            "stopped at cycle 20\nbefore cycle 20: x=21, pc 10: addx -1 [0/2]\n"
+        );
+        assert_eq!(
+            debugger.run("peek").unwrap(),
+            "signal: 0\n##..##..##..##..##.\n"
+        );
+        debugger.run("step").unwrap();
+        assert!(debugger.run("peek").unwrap().starts_with("signal: 420\n"));
+        assert_eq!(debugger.run("c").unwrap(), "halted after cycle 240: x=17\n");
+    }
+}
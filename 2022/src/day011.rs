@@ -0,0 +1,392 @@
+use aoc::{debug, ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+type Id = usize;
+
+/// The right hand side of a monkey's `new = ...`, in terms of the `old`
+/// worry. `*` binds tighter than `+`, as usual.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expr {
+    Old,
+    Value(u64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+use Expr::{Add, Mul, Old, Value};
+
+fn add(a: Expr, b: Expr) -> Expr {
+    Add(Box::new(a), Box::new(b))
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    Mul(Box::new(a), Box::new(b))
+}
+
+impl Expr {
+    fn eval(&self, old: u64) -> u64 {
+        match self {
+            Old => old,
+            Value(n) => *n,
+            Add(a, b) => a.eval(old) + b.eval(old),
+            Mul(a, b) => a.eval(old) * b.eval(old),
+        }
+    }
+
+    /// The same expression with the constants folded and the adding of
+    /// nothing, multiplying by one and the like taken out.
+    fn simplify(&self) -> Expr {
+        match self {
+            Old | Value(_) => self.clone(),
+            Add(a, b) => match (a.simplify(), b.simplify()) {
+                (Value(0), e) | (e, Value(0)) => e,
+                (Value(x), Value(y)) => x.checked_add(y).map_or(add(Value(x), Value(y)), Value),
+                (a, b) if a == b => mul(a, Value(2)),
+                (a, b) => add(a, b),
+            },
+            Mul(a, b) => match (a.simplify(), b.simplify()) {
+                (Value(0), _) | (_, Value(0)) => Value(0),
+                (Value(1), e) | (e, Value(1)) => e,
+                (Value(x), Value(y)) => x.checked_mul(y).map_or(mul(Value(x), Value(y)), Value),
+                (a, b) => mul(a, b),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // a sum being multiplied needs its brackets, though they can't be
+        // read back in
+        let factor = |e: &Expr, f: &mut fmt::Formatter| match e {
+            Add(..) => write!(f, "({e})"),
+            _ => write!(f, "{e}"),
+        };
+        match self {
+            Old => write!(f, "old"),
+            Value(n) => write!(f, "{n}"),
+            Add(a, b) => write!(f, "{a} + {b}"),
+            Mul(a, b) => {
+                factor(a, f)?;
+                write!(f, " * ")?;
+                factor(b, f)
+            }
+        }
+    }
+}
+
+/// Reads `expr`, a piece of `line`, as words separated by single spaces.
+fn parse_expr(line: &str, expr: &str) -> Result<Expr, ParseError> {
+    let operand = |word: &str| match word {
+        "old" => Ok(Old),
+        _ => word
+            .parse()
+            .map(Value)
+            .map_err(|_| ParseError::new("operand is not a number", line, word)),
+    };
+    let mut words = expr.split(' ');
+    let mut sum = None;
+    let mut product = operand(words.next().unwrap_or(expr))?;
+    while let Some(operator) = words.next() {
+        if operator != "+" && operator != "*" {
+            return Err(ParseError::new("unknown operator", line, operator));
+        }
+        let word = words
+            .next()
+            .ok_or_else(|| ParseError::new("missing operand", line, operator))?;
+        let rhs = operand(word)?;
+        if operator == "*" {
+            product = mul(product, rhs);
+        } else {
+            sum = Some(match sum {
+                Some(sum) => add(sum, product),
+                None => product,
+            });
+            product = rhs;
+        }
+    }
+    Ok(match sum {
+        Some(sum) => add(sum, product),
+        None => product,
+    })
+}
+
+#[derive(Clone, Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    op: Expr,
+    modulus: u64,
+    accept: Id,
+    reject: Id,
+    looks: usize,
+}
+
+type Troop = Vec<Monkey>;
+
+fn last_number<T: FromStr>(line: &str, what: &str) -> Result<T, ParseError> {
+    let word = line.rsplit(' ').next().unwrap_or(line);
+    word.parse()
+        .map_err(|_| ParseError::new(format!("{what} is not a number"), line, word))
+}
+
+fn parse_troop(input: &str) -> Result<Troop, ParseError> {
+    let mut troop = Vec::new();
+    let mut items = None;
+    let mut op = None;
+    let mut modulus = None;
+    let mut accept = None;
+    let mut started = false;
+    for (i, line) in input.lines().enumerate() {
+        let at = move |e: ParseError| e.below(i);
+        let missing = |what: &str| at(ParseError::line(format!("monkey has no {what}"), line));
+        if line.starts_with("Monkey ") {
+            if started {
+                return Err(at(ParseError::line(
+                    "started a new monkey in the middle",
+                    line,
+                )));
+            }
+            started = true;
+        } else if let Some(list) = line.strip_prefix("  Starting items: ") {
+            items = Some(
+                list.split(", ")
+                    .map(|s| {
+                        s.parse()
+                            .map_err(|_| at(ParseError::new("item is not a number", line, s)))
+                    })
+                    .collect::<Result<Vec<u64>, _>>()?,
+            );
+        } else if let Some(expr) = line.strip_prefix("  Operation: new = ") {
+            op = Some(parse_expr(line, expr).map_err(at)?.simplify());
+        } else if line.starts_with("  Test: divisible by ") {
+            modulus = Some(last_number(line, "modulus").map_err(at)?);
+        } else if line.starts_with("    If true: throw to monkey ") {
+            accept = Some(last_number(line, "accept target").map_err(at)?);
+        } else if line.starts_with("    If false: throw to monkey ") {
+            let reject = last_number(line, "reject target").map_err(at)?;
+
+            troop.push(Monkey {
+                items: items.ok_or_else(|| missing("items"))?,
+                op: op.ok_or_else(|| missing("operation"))?,
+                modulus: modulus.ok_or_else(|| missing("modulus"))?,
+                accept: accept.ok_or_else(|| missing("accept target"))?,
+                reject,
+                looks: 0,
+            });
+            items = None;
+            op = None;
+            modulus = None;
+            accept = None;
+            started = false;
+        } else if !line.trim().is_empty() {
+            return Err(at(ParseError::line("unrecognized monkey line", line)));
+        }
+    }
+    if started {
+        let (i, line) = input.lines().enumerate().last().unwrap();
+        return Err(ParseError::line("monkey ends before its reject target", line).below(i));
+    }
+    Ok(troop)
+}
+
+fn round(troop: &mut Troop, fidget: &dyn Fn(u64) -> u64) {
+    for i in 0..troop.len() {
+        // the monkey lets go of everything it holds before throwing any of
+        // it, so a throw to itself waits for the next round
+        let items = std::mem::take(&mut troop[i].items);
+        troop[i].looks += items.len();
+        for value in items {
+            let monkey = &troop[i];
+            let updated_value = fidget(monkey.op.eval(value));
+            let target = if updated_value.is_multiple_of(monkey.modulus) {
+                monkey.accept
+            } else {
+                monkey.reject
+            };
+            troop[target].items.push(updated_value);
+        }
+    }
+}
+
+fn monkey_business(troop: &Troop) -> usize {
+    let mut looks = troop.iter().map(|m| m.looks).collect::<Vec<usize>>();
+    looks.sort();
+    looks.reverse();
+    assert!(
+        looks.len() >= 2,
+        "too few monkeys to comtemplate any monkey business"
+    );
+    looks[0] * looks[1]
+}
+
+pub struct Day011 {
+    troop: Troop,
+}
+
+impl Solution for Day011 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let troop = parse_troop(input)?;
+        for (i, monkey) in troop.iter().enumerate() {
+            debug!("monkey {i}: new = {}", monkey.op);
+        }
+        Ok(Day011 { troop })
+    }
+
+    fn part1(&self) -> usize {
+        let troop = &mut self.troop.clone();
+        for _ in 0..20 {
+            round(troop, &|w| w / 3);
+        }
+        monkey_business(troop)
+    }
+
+    fn part2(&self) -> usize {
+        let troop = &mut self.troop.clone();
+        // from https://github.com/rtsuk/advent_of_code_2022/blob/master/src/bin/day11.rs#L221
+        let lcm: u64 = troop.iter().map(|m| m.modulus).product();
+        for _ in 0..10_000 {
+            round(troop, &|w| w % lcm);
+        }
+        monkey_business(troop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = include_str!("../input/011-sample-1.txt");
+
+    #[test]
+    fn test_parse_troop() {
+        let troop: Troop = parse_troop(SAMPLE).unwrap();
+        assert_eq!(troop.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let bad = SAMPLE.replacen("new = old * 19", "new = old / 19", 1);
+        let e = parse_troop(&bad).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 24, "/"));
+        let bad = SAMPLE.replacen("new = old * 19", "new = old * nineteen", 1);
+        let e = parse_troop(&bad).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 26, "nineteen"));
+        let truncated = &SAMPLE[..SAMPLE.find("    If false").unwrap()];
+        let e = parse_troop(truncated).err().unwrap();
+        assert_eq!(e.line, 5);
+        assert_eq!(e.reason, "monkey ends before its reject target");
+    }
+
+    #[test]
+    fn test_parse_expr() {
+        let parse = |expr: &str| parse_expr(expr, expr).unwrap();
+        assert_eq!(parse("old * 19"), mul(Old, Value(19)));
+        assert_eq!(parse("old"), Old);
+        assert_eq!(
+            parse("old + 2 * old + 1"),
+            add(add(Old, mul(Value(2), Old)), Value(1))
+        );
+        for expr in ["old * old", "old + 6", "3 * old * old + old + 1"] {
+            assert_eq!(parse(expr).to_string(), expr);
+        }
+        assert_eq!(mul(add(Old, Value(1)), Old).to_string(), "(old + 1) * old");
+        let e = parse_expr("old +", "old +").unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (5, "missing operand"));
+        let e = parse_expr("old ** 2", "old ** 2").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (5, "**"));
+    }
+
+    #[test]
+    fn test_eval() {
+        let expr = parse_expr("", "old * old + 3 * old + 2").unwrap();
+        assert_eq!(expr.eval(0), 2);
+        assert_eq!(expr.eval(5), 42);
+        assert_eq!(mul(add(Old, Value(1)), add(Old, Value(2))).eval(5), 42);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let simplify = |expr: &str| parse_expr("", expr).unwrap().simplify().to_string();
+        assert_eq!(simplify("old + old"), "old * 2");
+        assert_eq!(simplify("old * 1 + 0"), "old");
+        assert_eq!(simplify("2 * 3 + old"), "6 + old");
+        assert_eq!(simplify("old * 0 + 7"), "7");
+        assert_eq!(simplify("old * old + 4"), "old * old + 4");
+        assert_eq!(
+            simplify("18446744073709551615 + 1"),
+            "18446744073709551615 + 1"
+        );
+    }
+
+    #[test]
+    fn test_old_plus_old() {
+        let doubling = SAMPLE.replacen("new = old * old", "new = old + old", 1);
+        let troop = &mut parse_troop(&doubling).unwrap();
+        assert_eq!(troop[2].op, mul(Old, Value(2)));
+        round(troop, &|w| w / 3);
+        assert_eq!(troop[2].looks, 3);
+        assert_eq!(troop[0].items, [20, 23, 27, 26]);
+    }
+
+    #[test]
+    fn test_round() {
+        let troop = &mut parse_troop(SAMPLE).unwrap();
+        round(troop, &|w| w / 3);
+        assert_eq!(troop[0].items, [20, 23, 27, 26]);
+        assert_eq!(troop[1].items, [2080, 25, 167, 207, 401, 1046]);
+        assert!(troop[2].items.is_empty());
+        assert!(troop[3].items.is_empty());
+    }
+
+    #[test]
+    fn test_monkey_business() {
+        let troop = &mut parse_troop(SAMPLE).unwrap();
+        for _ in 0..20 {
+            round(troop, &|w| w / 3);
+        }
+        assert_eq!(troop[0].items, [10, 12, 14, 26, 34]);
+        assert_eq!(troop[1].items, [245, 93, 53, 199, 115]);
+        assert!(troop[2].items.is_empty());
+        assert!(troop[3].items.is_empty());
+        assert_eq!(monkey_business(troop), 10605);
+    }
+
+    #[test]
+    fn test_fidget() {
+        let troop = &mut parse_troop(SAMPLE).unwrap();
+        let lcm: u64 = troop.iter().map(|m| m.modulus).product();
+        println!("{lcm}");
+        let clocks = |w| w % lcm;
+        round(troop, &clocks);
+        assert_eq!(troop[0].looks, 2);
+        assert_eq!(troop[1].looks, 4);
+        assert_eq!(troop[2].looks, 3);
+        assert_eq!(troop[3].looks, 6);
+        for _ in 1..20 {
+            round(troop, &clocks);
+        }
+        assert_eq!(troop[0].looks, 99);
+        assert_eq!(troop[1].looks, 97);
+        assert_eq!(troop[2].looks, 8);
+        assert_eq!(troop[3].looks, 103);
+        for _ in 20..1_000 {
+            round(troop, &clocks);
+        }
+        assert_eq!(troop[0].looks, 5204);
+        assert_eq!(troop[1].looks, 4792);
+        assert_eq!(troop[2].looks, 199);
+        assert_eq!(troop[3].looks, 5192);
+    }
+
+    #[test]
+    fn test_big_worres() {
+        let troop = &mut parse_troop(SAMPLE).unwrap();
+        let lcm: u64 = troop.iter().map(|m| m.modulus).product();
+        for _ in 0..10_000 {
+            round(troop, &|w| w % lcm);
+        }
+        assert_eq!(monkey_business(troop), 2713310158);
+    }
+}
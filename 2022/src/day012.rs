@@ -0,0 +1,177 @@
+use aoc::image::{gradient, Image, ORANGE, RED, WHITE};
+use aoc::search::{astar, Path};
+use aoc::{Grid, ParseError, Pos, Solution};
+
+#[derive(Debug)]
+struct Map {
+    start: Pos,
+    end: Pos,
+    heights: Grid<usize>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let letters = Grid::try_parse(input, |c| matches!(c, 'S' | 'E' | 'a'..='z').then_some(c))?;
+        let missing = |what: &str| {
+            let (i, last) = input.lines().enumerate().last().unwrap_or((0, ""));
+            ParseError::line(format!("map ends without {what}"), last).below(i)
+        };
+        let start = letters
+            .position(|&c| c == 'S')
+            .ok_or_else(|| missing("a start"))?;
+        let end = letters
+            .position(|&c| c == 'E')
+            .ok_or_else(|| missing("an end"))?;
+        let heights = letters.map(|&c| {
+            let c = match c {
+                'S' => 'a',
+                'E' => 'z',
+                c => c,
+            };
+            c as usize - 'a' as usize
+        });
+        Ok(Map {
+            start,
+            end,
+            heights,
+        })
+    }
+
+    fn get_height(&self, p: &Pos) -> usize {
+        self.heights[*p]
+    }
+
+    fn dist(&self, p: &Pos, q: &Pos) -> usize {
+        p.0.abs_diff(q.0) + p.1.abs_diff(q.1)
+    }
+
+    fn h(&self, p: &Pos) -> usize {
+        self.dist(&self.end, p)
+    }
+}
+
+fn scenic_path(map: &Map) -> usize {
+    scenic_route(map).expect("found no paths at all").cost
+}
+
+fn scenic_route(map: &Map) -> Option<Path<Pos>> {
+    map.heights
+        .indexed()
+        .filter(|(_, h)| **h == 0)
+        .filter_map(|(p, _)| route(map, p))
+        .min_by_key(|path| path.cost)
+}
+
+fn shortest_path(map: &Map) -> usize {
+    shortest_path_from_pos(map, map.start)
+}
+
+fn shortest_path_from_pos(map: &Map, start: Pos) -> usize {
+    route(map, start).map_or(usize::MAX, |path| path.cost)
+}
+
+fn route(map: &Map, start: Pos) -> Option<Path<Pos>> {
+    let climbable = |p: &Pos| {
+        let limit = map.get_height(p) + 1;
+        map.heights
+            .neighbours4(*p)
+            .filter(move |q| map.get_height(q) <= limit)
+            .map(|q| (q, 1))
+    };
+    astar(start, climbable, |p| *p == map.end, |p| map.h(p))
+}
+
+/// The hill shaded from low and dark to high and white, with the way up
+/// from the start in red and the scenic way up in orange.
+fn picture(map: &Map) -> Image {
+    let mut image = Image::from_grid(&map.heights, |&h| {
+        gradient([20, 60, 20], WHITE, h as f64 / 25.0)
+    });
+    if let Some(path) = scenic_route(map) {
+        image.overlay(path.nodes, ORANGE);
+    }
+    if let Some(path) = route(map, map.start) {
+        image.overlay(path.nodes, RED);
+    }
+    image.scale(4)
+}
+
+pub struct Day012 {
+    map: Map,
+}
+
+impl Solution for Day012 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day012 {
+            map: Map::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        shortest_path(&self.map)
+    }
+
+    fn part2(&self) -> usize {
+        scenic_path(&self.map)
+    }
+
+    fn picture(&self) -> Option<Image> {
+        Some(picture(&self.map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi"#;
+
+    #[test]
+    fn test_parse_troop() {
+        let map: Map = Map::parse(SAMPLE).unwrap();
+        println!("{:?}", map);
+        assert_eq!(map.heights.height(), 5);
+        assert_eq!(map.heights.width(), 8);
+        assert_eq!(map.start, (0, 0));
+        assert_eq!(map.end, (5, 2));
+        let e = Map::parse(&SAMPLE.replace('E', "z")).unwrap_err();
+        assert_eq!((e.line, e.reason.as_str()), (5, "map ends without an end"));
+        let e = Map::parse("Sab\ncE!").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "!"));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let map: Map = Map::parse(SAMPLE).unwrap();
+        assert_eq!(shortest_path(&map), 31);
+    }
+
+    #[test]
+    fn test_scenic_path() {
+        let map: Map = Map::parse(SAMPLE).unwrap();
+        assert_eq!(scenic_path(&map), 29);
+    }
+
+    #[test]
+    fn test_picture() {
+        let map: Map = Map::parse(SAMPLE).unwrap();
+        let image = picture(&map);
+        assert_eq!((image.width(), image.height()), (32, 20));
+        let at = |x: usize, y: usize| image.get((4 * x, 4 * y));
+        // the red way up runs from the start to the end
+        assert_eq!(at(0, 0), Some(RED));
+        assert_eq!(at(5, 2), Some(RED));
+        // and the scenic way starts from the bottom corner and joins it
+        assert_eq!(at(0, 4), Some(ORANGE));
+        assert_eq!(at(1, 3), Some(ORANGE));
+        // the rest is left shaded by height
+        assert_eq!(at(0, 1), Some([20, 60, 20]));
+        assert_eq!(at(1, 2), Some(gradient([20, 60, 20], WHITE, 2.0 / 25.0)));
+    }
+}
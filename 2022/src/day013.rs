@@ -1,15 +1,14 @@
+use aoc::{ParseError, Solution};
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
 use pest::iterators::Pair;
 use pest::Parser;
+use pest_derive::Parser;
 use std::cmp::{Ordering, Ordering::Equal, Ordering::Greater, Ordering::Less};
-use std::fs;
 use std::str::Lines;
-#[macro_use]
-extern crate pest_derive;
 
 #[derive(Parser)]
-#[grammar = "bin/013.pest"]
+#[grammar = "day013.pest"]
 struct ListParser;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -17,7 +16,7 @@ enum Item {
     Number(i32),
     List(Vec<Item>),
 }
-use crate::Item::{List, Number};
+use Item::{List, Number};
 
 impl PartialOrd for Item {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -60,7 +59,7 @@ impl Ord for Item {
 
 fn unpack_token(token: Pair<Rule>) -> Item {
     match token.as_rule() {
-        Rule::number => return Number(token.as_str().parse().expect("parser says so")),
+        Rule::number => Number(token.as_str().parse().expect("parser says so")),
         Rule::list => {
             let mut items = Vec::new();
             for inner_token in token.into_inner() {
@@ -110,18 +109,32 @@ fn find_decoder_key(items: &mut Vec<Item>) -> usize {
         .product::<usize>()
 }
 
-fn main() {
-    let input = fs::read_to_string("input/013.txt").expect("file read error");
-    println!("there are {} lines", input.lines().count());
-    let mut items: Vec<Item> = parse_packets(input.lines());
-    println!("ordering score is {}", count_correct_orders(&items));
+pub struct Day013 {
+    items: Vec<Item>,
+}
+
+impl Solution for Day013 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day013 {
+            items: parse_packets(input.lines()),
+        })
+    }
 
-    println!("decoder key is {}", find_decoder_key(&mut items));
+    fn part1(&self) -> usize {
+        count_correct_orders(&self.items)
+    }
+
+    fn part2(&self) -> usize {
+        find_decoder_key(&mut self.items.clone())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"[1,1,3,1,1]
 [1,1,5,1,1]
 
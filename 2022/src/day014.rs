@@ -0,0 +1,283 @@
+use itertools::Itertools;
+use aoc::{parse_lines, ParseError, Point2, Screen, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+type Pos = Point2<i32>;
+
+struct Cave {
+    rock: HashSet<Pos>,
+    sand: HashSet<Pos>,
+    bottom: i32,
+    limitless: bool,
+}
+
+struct Path {
+    intersections: Vec<Pos>,
+}
+
+impl FromStr for Path {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let number = |n: &str| {
+            n.parse()
+                .map_err(|_| ParseError::new("coordinate is not a number", line, n))
+        };
+        let mut intersections: Vec<Pos> = Vec::new();
+        for point in line.split(" -> ") {
+            let (x, y) = point
+                .split_once(',')
+                .ok_or_else(|| ParseError::new("point is not x,y", line, point))?;
+            let p = Pos::new(number(x)?, number(y)?);
+            if let Some(last) = intersections.last() {
+                if last.x != p.x && last.y != p.y {
+                    return Err(ParseError::new("diagonal path", line, point));
+                }
+            }
+            intersections.push(p);
+        }
+        Ok(Path { intersections })
+    }
+}
+
+impl Cave {
+    fn from(paths: &[Path]) -> Self {
+        let mut bottom = 0;
+        let mut rock = HashSet::new();
+        for path in paths {
+            for (start, end) in path.intersections.iter().tuple_windows() {
+                let delta = (*end - *start).signum();
+                if delta.x.abs() == 1 && delta.y.abs() == 1 {
+                    panic!("diagonal path");
+                }
+                let mut p = *start;
+                while p != *end {
+                    rock.insert(p);
+                    p += delta;
+                    bottom = bottom.max(p.y);
+                }
+                rock.insert(p);
+                bottom = bottom.max(p.y);
+            }
+        }
+        Cave {
+            rock,
+            sand: HashSet::new(),
+            bottom,
+            limitless: true,
+        }
+    }
+
+    fn assume_hard_floor(&mut self, gap: i32) {
+        self.bottom += gap - 1;
+        self.limitless = false;
+    }
+
+    fn fill(&mut self, start: &Pos) -> usize {
+        let mut n = 0;
+        while self.drop_grain(start).is_some() {
+            n += 1;
+        }
+        n
+    }
+
+    fn drop_grain(&mut self, start: &Pos) -> Option<Pos> {
+        if self.rock.contains(start) {
+            return None;
+        }
+        let mut p = *start;
+        while p.y < self.bottom {
+            for delta in [
+                Some(Pos::new(0, 1)),
+                Some(Pos::new(-1, 1)),
+                Some(Pos::new(1, 1)),
+                None,
+            ]
+            .iter()
+            {
+                match delta {
+                    Some(d) => {
+                        let q = p + *d;
+                        if !self.rock.contains(&q) {
+                            p = q;
+                            break;
+                        }
+                    }
+                    None => {
+                        self.rock.insert(p);
+                        self.sand.insert(p);
+                        return Some(p);
+                    }
+                }
+            }
+        }
+        if self.limitless {
+            None
+        } else {
+            self.rock.insert(p);
+            self.sand.insert(p);
+            Some(p)
+        }
+    }
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let left = self.rock.iter().map(|p| p.x).min().unwrap_or(500).min(500);
+        let right = self.rock.iter().map(|p| p.x).max().unwrap_or(500).max(500);
+        for y in 0..=self.bottom {
+            for x in left..=right {
+                let p = Pos::new(x, y);
+                let c = if self.sand.contains(&p) {
+                    'o'
+                } else if self.rock.contains(&p) {
+                    '#'
+                } else if p == Pos::new(500, 0) {
+                    '+'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        if !self.limitless {
+            writeln!(f, "{}", "#".repeat((right - left + 1) as usize))?;
+        }
+        writeln!(f, "sand: {}", self.sand.len())
+    }
+}
+
+pub struct Day014 {
+    scan: Vec<Path>,
+}
+
+impl Solution for Day014 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day014 {
+            scan: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        let mut cave = Cave::from(&self.scan);
+        cave.fill(&Pos::new(500, 0))
+    }
+
+    fn part2(&self) -> usize {
+        let mut cave = Cave::from(&self.scan);
+        cave.assume_hard_floor(2);
+        cave.fill(&Pos::new(500, 0))
+    }
+
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool {
+        let mut cave = Cave::from(&self.scan);
+        if part == 2 {
+            cave.assume_hard_floor(2);
+        }
+        while cave.drop_grain(&Pos::new(500, 0)).is_some() && screen.show(&cave.to_string()) {}
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9"#;
+
+    #[test]
+    fn test_from() {
+        let scan: Vec<Path> = parse_lines(SAMPLE).unwrap();
+        assert_eq!(scan.len(), 2);
+        assert_eq!(scan[0].intersections.len(), 3);
+        assert_eq!(scan[1].intersections.len(), 4);
+        assert_eq!(scan[1].intersections[2], Pos::new(502, 9));
+        let e = parse_lines::<Path>("498,4 -> 498,6\n503,4 -> 502,x")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 14, "x"));
+        let e = parse_lines::<Path>("498,4 -> 500,6").err().unwrap();
+        assert_eq!((e.column, e.reason.as_str()), (10, "diagonal path"));
+        assert!(parse_lines::<Path>("498,4 -> 4986").is_err());
+    }
+
+    #[test]
+    fn test_build_cave() {
+        let scan: Vec<Path> = parse_lines(SAMPLE).unwrap();
+        let cave = Cave::from(&scan);
+        assert!(cave.rock.contains(&Pos::new(496, 6)));
+        assert!(cave.rock.contains(&Pos::new(498, 4)));
+        assert!(cave.rock.contains(&Pos::new(502, 7)));
+        assert!(cave.rock.contains(&Pos::new(503, 4)));
+        assert!(!cave.rock.contains(&Pos::new(500, 6)));
+        assert_eq!(cave.rock.len(), 20);
+        assert_eq!(cave.bottom, 9);
+    }
+
+    #[test]
+    fn test_drop_grain() {
+        let scan: Vec<Path> = parse_lines(SAMPLE).unwrap();
+        let mut cave = Cave::from(&scan);
+        assert_eq!(cave.drop_grain(&Pos::new(500, 0)), Some(Pos::new(500, 8)));
+        assert_eq!(cave.drop_grain(&Pos::new(500, 0)), Some(Pos::new(499, 8)));
+        for _ in 2..4 {
+            cave.drop_grain(&Pos::new(500, 0));
+        }
+        assert_eq!(cave.drop_grain(&Pos::new(500, 0)), Some(Pos::new(498, 8)));
+        for _ in 5..21 {
+            cave.drop_grain(&Pos::new(500, 0));
+        }
+        assert_eq!(cave.drop_grain(&Pos::new(500, 0)), Some(Pos::new(500, 2)));
+        assert!(cave.drop_grain(&Pos::new(500, 0)).is_some());
+        assert!(cave.drop_grain(&Pos::new(500, 0)).is_some());
+        assert!(cave.drop_grain(&Pos::new(500, 0)).is_none());
+    }
+
+    #[test]
+    fn test_fill_cave() {
+        let scan: Vec<Path> = parse_lines(SAMPLE).unwrap();
+        let mut cave = Cave::from(&scan);
+        assert_eq!(cave.fill(&Pos::new(500, 0)), 24);
+    }
+
+    #[test]
+    fn test_fill_finite_cave() {
+        let scan: Vec<Path> = parse_lines(SAMPLE).unwrap();
+        let mut cave = Cave::from(&scan);
+        cave.assume_hard_floor(2);
+        assert_eq!(cave.fill(&Pos::new(500, 0)), 93);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let day = Day014::parse(SAMPLE).unwrap();
+        let mut frames = Vec::new();
+        assert!(day.visualize(1, &mut frames));
+        assert_eq!(frames.len(), 24);
+        assert_eq!(
+            frames[23],
+            r#"......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+sand: 24
+"#
+        );
+        let mut frames = Vec::new();
+        day.visualize(2, &mut frames);
+        assert_eq!(frames.len(), 93);
+        assert!(frames[92].ends_with("#\nsand: 93\n"));
+    }
+}
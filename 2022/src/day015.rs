@@ -0,0 +1,210 @@
+use aoc::{parallel, parse_line, parse_lines, Interval, IntervalSet, ParseError, Point2, Solution};
+use itertools::Itertools;
+
+parse_line! {
+    #[derive(Debug)]
+    struct Reading {
+        sx: i32,
+        sy: i32,
+        bx: i32,
+        by: i32,
+    } = "Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}";
+}
+
+type Coord = Point2<i32>;
+
+#[derive(Debug)]
+struct Sensor {
+    p: Coord,
+    b: Coord,
+    r: i32,
+}
+
+impl From<Reading> for Sensor {
+    fn from(reading: Reading) -> Self {
+        let p = Coord::new(reading.sx, reading.sy);
+        let b = Coord::new(reading.bx, reading.by);
+        Sensor {
+            p,
+            b,
+            r: p.manhattan(&b),
+        }
+    }
+}
+
+fn parse_sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    Ok(parse_lines::<Reading>(input)?
+        .into_iter()
+        .map(Sensor::from)
+        .collect())
+}
+
+impl Sensor {
+    fn coverage_at(&self, row: i32) -> Option<Interval<i32>> {
+        let h = (self.p.y - row).abs();
+        Interval::new(self.p.x - (self.r - h), self.p.x + (self.r - h))
+    }
+}
+
+fn spans_at(sensors: &[Sensor], row: i32) -> IntervalSet<i32> {
+    sensors.iter().filter_map(|s| s.coverage_at(row)).collect()
+}
+
+fn covered_area_at(sensors: &[Sensor], row: i32) -> usize {
+    let total_area = spans_at(sensors, row).len() as usize;
+    let beacons_on_row = sensors
+        .iter()
+        .filter(|s| s.b.y == row)
+        .map(|s| s.b)
+        .unique()
+        .count();
+    total_area - beacons_on_row
+}
+
+fn holes_on_row(sensors: &[Sensor], row: i32, window: &Interval<i32>) -> Option<Coord> {
+    let uncovered = IntervalSet::from(*window).difference(&spans_at(sensors, row));
+    uncovered.first().map(|x| Coord::new(x, row))
+}
+
+fn find_frequency(sensors: &[Sensor], window: &Interval<i32>) -> Option<i64> {
+    let rows = 0..window.len() as usize;
+    let hole = parallel::find_first(rows, |i| {
+        holes_on_row(sensors, window.lo + i as i32, window)
+    })?;
+    Some(4_000_000 * hole.x as i64 + hole.y as i64)
+}
+
+pub struct Day015 {
+    sensors: Vec<Sensor>,
+}
+
+impl Solution for Day015 {
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day015 {
+            sensors: parse_sensors(input)?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        covered_area_at(&self.sensors, 2_000_000)
+    }
+
+    fn part2(&self) -> i64 {
+        find_frequency(
+            &self.sensors,
+            &Interval {
+                lo: 0,
+                hi: 4_000_000,
+            },
+        )
+        .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
+
+    #[test]
+    fn test_pattern() {
+        // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        let reading: Reading = SAMPLE.lines().next().unwrap().parse().unwrap();
+        assert_eq!(
+            (reading.sx, reading.sy, reading.bx, reading.by),
+            (2, 18, -2, 15)
+        );
+        let e = "$ cd /".parse::<Reading>().unwrap_err();
+        assert_eq!(e.reason, "expected `Sensor at x=`");
+        let e = parse_sensors("Sensor at x=2, y=18: closest beacon is at x=-2, y=1.5").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (51, "1.5"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(sensors[0].p, Coord::new(2, 18));
+        assert_eq!(sensors[0].b, Coord::new(-2, 15));
+        assert_eq!(sensors[1].p, Coord::new(9, 16));
+        assert_eq!(sensors[1].b, Coord::new(10, 16));
+        assert_eq!(sensors[6].p, Coord::new(8, 7));
+        assert_eq!(sensors[6].r, 9);
+    }
+
+    #[test]
+    fn test_coverage_at() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(sensors[6].coverage_at(10), Some(Interval { lo: 2, hi: 14 }));
+        assert_eq!(sensors[6].coverage_at(16), Some(Interval { lo: 8, hi: 8 }));
+        assert_eq!(sensors[6].coverage_at(17), None);
+        assert_eq!(sensors[6].coverage_at(4), Some(Interval { lo: 2, hi: 14 }));
+        assert_eq!(sensors[6].coverage_at(-2), Some(Interval { lo: 8, hi: 8 }));
+        assert_eq!(sensors[6].coverage_at(-3), None);
+    }
+
+    #[test]
+    fn test_spans_at() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(
+            spans_at(&sensors, 10).intervals(),
+            &[Interval { lo: -2, hi: 24 }]
+        );
+        assert_eq!(
+            spans_at(&sensors, 11).intervals(),
+            &[Interval { lo: -3, hi: 13 }, Interval { lo: 15, hi: 25 }]
+        );
+    }
+
+    #[test]
+    fn test_covered_area_at() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(covered_area_at(&sensors, 10), 26);
+    }
+
+    #[test]
+    fn test_holes_on_row() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(holes_on_row(&sensors, 9, &Interval { lo: 0, hi: 20 }), None);
+        assert_eq!(
+            holes_on_row(&sensors, 10, &Interval { lo: 0, hi: 20 }),
+            None
+        );
+        assert_eq!(
+            holes_on_row(&sensors, 11, &Interval { lo: 0, hi: 20 }),
+            Some(Coord::new(14, 11))
+        );
+        assert_eq!(
+            holes_on_row(&sensors, 10, &Interval { lo: 0, hi: 25 }),
+            Some(Coord::new(25, 10))
+        );
+        assert_eq!(
+            holes_on_row(&sensors, 9, &Interval { lo: 0, hi: 25 }),
+            Some(Coord::new(24, 9))
+        );
+    }
+
+    #[test]
+    fn test_find_frequency() {
+        let sensors = parse_sensors(SAMPLE).unwrap();
+        assert_eq!(
+            find_frequency(&sensors, &Interval { lo: 0, hi: 20 }),
+            Some(56000011)
+        );
+    }
+}
@@ -1,8 +1,10 @@
+use aoc::search::all_pairs;
+use aoc::{debug, parse_lines, trace, ParseError, Solution, Unsolved};
 use lazy_static::lazy_static;
-use priority_queue::DoublePriorityQueue;
 use regex::Regex;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::{fmt, fs};
+use std::fmt;
+use std::str::FromStr;
 
 type Key = u64;
 
@@ -23,6 +25,7 @@ struct Network {
 #[derive(Clone, Copy)]
 enum Action {
     Open(Key),
+    #[allow(dead_code)] // the planner warps between valves, only the tests walk
     Move(Key),
     Wait,
 }
@@ -48,7 +51,7 @@ impl Plan {
     fn empty(score: usize) -> Self {
         Plan {
             actions: VecDeque::new(),
-            score: score,
+            score,
         }
     }
 
@@ -73,13 +76,13 @@ impl Plan {
                 Open(valve) => {
                     if valve != &here.key {
                         // warp
-                        println!("warp to {}", Valve::label(*valve));
+                        trace!("warp to {}", Valve::label(*valve));
                         let steps = net.routes.get(&(here.key, *valve)).unwrap();
                         total += rate * steps;
                         time += steps;
                         here = net.valves.get(valve).expect("unknwon node");
                     } else {
-                        println!("open {}", Valve::label(*valve));
+                        trace!("open {}", Valve::label(*valve));
                     }
                     if here.open {
                         panic!("Valve {} is already open!", Valve::label(here.key));
@@ -95,7 +98,7 @@ impl Plan {
                         );
                     }
                     here = net.valves.get(to).expect("unknwon node");
-                    println!("move {}", Valve::label(*to));
+                    trace!("move {}", Valve::label(*to));
                 }
                 Wait => {
                     total += rate * (30 - time);
@@ -140,118 +143,67 @@ lazy_static! {
     .unwrap();
 }
 
-impl From<&str> for Valve {
-    fn from(s: &str) -> Self {
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
         // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-        match RE.captures(s) {
-            Some(cap) => {
-                let label = cap.get(1).expect("too few capture groups").as_str();
-                let key = Valve::key(label);
-                let rate = cap
-                    .get(2)
-                    .expect("too few capture groups")
-                    .as_str()
-                    .parse::<usize>()
-                    .expect("not a capture groups");
-                let tunnels = cap
-                    .get(3)
-                    .expect("too few capture groups")
-                    .as_str()
-                    .split(", ")
-                    .map(Valve::key)
-                    .map(|k| (k, 1))
-                    .collect::<HashMap<Key, usize>>();
-                let mut open = false;
-                if rate == 0 {
-                    open = true;
-                }
-                Valve {
-                    key,
-                    rate,
-                    tunnels,
-                    open,
-                }
-            }
-            None => panic!("unparsable input: {}", s),
-        }
+        let cap = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::line("unrecognized valve", s))?;
+        let key = Valve::key(&cap[1]);
+        let rate = cap[2]
+            .parse::<usize>()
+            .map_err(|_| ParseError::new("flow rate out of range", s, &cap[2]))?;
+        let tunnels = cap[3]
+            .split(", ")
+            .map(Valve::key)
+            .map(|k| (k, 1))
+            .collect::<HashMap<Key, usize>>();
+        Ok(Valve {
+            key,
+            rate,
+            tunnels,
+            open: rate == 0,
+        })
     }
 }
 
-impl From<std::str::Lines<'_>> for Network {
-    fn from(input: std::str::Lines) -> Self {
-        let mut valves = HashMap::new();
-        for line in input {
-            let valve = Valve::from(line);
-            valves.insert(valve.key, valve);
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let list: Vec<Valve> = parse_lines(input)?;
+        let keys: Vec<Key> = list.iter().map(|v| v.key).collect();
+        for ((i, line), valve) in input.lines().enumerate().zip(&list) {
+            if let Some(k) = valve.tunnels.keys().filter(|k| !keys.contains(k)).min() {
+                let reason = format!("tunnel to unknown valve {}", Valve::label(*k));
+                return Err(ParseError::line(reason, line).below(i));
+            }
         }
+        let valves = list.into_iter().map(|v| (v.key, v)).collect();
         let routes = HashMap::new();
         let mut net = Network { valves, routes };
         net.find_routes();
-        net
+        for ((i, line), a) in input.lines().enumerate().zip(&keys) {
+            if let Some(b) = keys.iter().find(|b| !net.routes.contains_key(&(*a, **b))) {
+                let reason = format!("no path from {} to {}", Valve::label(*a), Valve::label(*b));
+                return Err(ParseError::line(reason, line).below(i));
+            }
+        }
+        Ok(net)
     }
 }
 
 impl Network {
-    fn h(&self, f: Key, t: Key) -> usize {
-        if f == t {
-            return 0;
-        }
-        if self.valves.get(&f).unwrap().tunnels.contains_key(&t) {
-            return 1;
-        } else {
-            return 2;
-        }
+    fn tunnels(&self, key: &Key) -> Vec<(Key, usize)> {
+        let valve = self.valves.get(key).expect("unknown valve");
+        valve.tunnels.iter().map(|(k, n)| (*k, *n)).collect()
     }
 
     fn find_routes(&mut self) {
-        for (ka, _) in self.valves.iter() {
-            for (kb, _) in self.valves.iter() {
-                if ka <= kb {
-                    if let Some(n) = self.shortest_path(*ka, *kb) {
-			self.routes.insert((*ka, *kb), n);
-			self.routes.insert((*kb, *ka), n);
-		    } else {
-			panic!("No path from {} to {}", Valve::label(*ka), Valve::label(*kb));
-		    }
-		}
-            }
-        }
-    }
-
-    fn shortest_path(&self, from: Key, to: Key) -> Option<usize> {
-        // https://en.wikipedia.org/wiki/A*_search_algorithm
-        let longest = self.valves.len() + 1;
-        let mut open = DoublePriorityQueue::new();
-        let current = from;
-        open.push(current, self.h(current, to));
-
-        let mut g_score = HashMap::new();
-        g_score.insert(current, 0usize);
-
-        let mut from = HashMap::new();
-        while !open.is_empty() {
-            let (current, _) = open.pop_min().expect("while says it's not empty");
-            if current == to {
-                // unwind
-                let mut path = Vec::new();
-                let mut p = current;
-                while let Some(q) = from.get(&p) {
-                    path.push(*q);
-                    p = *q;
-                }
-                path.reverse();
-                return Some(path.len());
-            }
-            for neighbor in self.valves.get(&current).unwrap().tunnels.keys() {
-                let tentative_g_score = 1 + g_score.get(&current).unwrap_or(&longest);
-                if tentative_g_score < *g_score.get(&neighbor).unwrap_or(&longest) {
-                    from.insert(*neighbor, current);
-                    g_score.insert(*neighbor, tentative_g_score);
-                    open.push(*neighbor, tentative_g_score + self.h(*neighbor, to));
-                }
-            }
-        }
-        None
+        let keys: Vec<Key> = self.valves.keys().copied().collect();
+        self.routes = all_pairs(&keys, |k| self.tunnels(k));
     }
 
     fn optimal_plan(&self, start: &str) -> usize {
@@ -268,9 +220,9 @@ impl Network {
             30, // depth
             &mut closed,
         );
-        println!("best plan is: {:?}", plan.actions);
-        println!("presumptive score is {}", plan.score);
-        println!("actual score is {}", plan.execute(self, &Valve::key("AA")));
+        debug!("best plan is: {:?}", plan.actions);
+        debug!("presumptive score is {}", plan.score);
+        debug!("actual score is {}", plan.execute(self, &Valve::key("AA")));
         plan.score
     }
 
@@ -299,9 +251,9 @@ impl Network {
         depth: usize,
         closed: &mut HashSet<Key>,
     ) -> Plan {
-	println!("{} @ {}", Valve::label(start), depth);
+	trace!("{} @ {}", Valve::label(start), depth);
         if depth == 0 {
-	    println!("end of time: {}", pressure);
+	    trace!("end of time: {}", pressure);
             return Plan::empty(pressure);
         }
         let start = self.valves.get(&start).expect("unknown valve");
@@ -326,12 +278,12 @@ impl Network {
         if let Some(steps) = self.routes.get(&(start.key, best.0)) {
 	    let steps = steps + 1;
 	    let pressure = pressure + (rate * steps);
-	    println!("stepping {} to {}", steps, Valve::label(best.0));
+	    trace!("stepping {} to {}", steps, Valve::label(best.0));
 	    let mut new_plan = self.optimal_plan_worker(best.0, pressure, rate, depth - steps, closed);
             if start.rate > 0 {
 		new_plan.insert(&Open(start.key));
             }
-            return new_plan;
+            new_plan
 	} else {
 	    // nowhere left to go
             let mut wait = Plan::empty(pressure);
@@ -340,25 +292,39 @@ impl Network {
             if start.rate > 0 {
                 wait.insert(&Open(start.key));
             }
-            return wait;
+            wait
 	}
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input/016.txt").expect("file read error");
-    let network: Network = Network::from(input.lines());
-    println!("there are {} valves", network.valves.len());
-    println!(
-        "the optimal plan releases {} inches of pressure",
-        network.optimal_plan("AA")
-    );
-    // 1745 is too low
+pub struct Day016 {
+    network: Network,
+}
+
+impl Solution for Day016 {
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day016 {
+            network: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        // 1745 is too low
+        self.network.optimal_plan("AA")
+    }
+
+    fn part2(&self) -> Unsolved {
+        Unsolved
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    use aoc::search::dijkstra;
     const SAMPLE: &str = r#"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
 Valve CC has flow rate=2; tunnels lead to valves DD, BB
@@ -390,6 +356,31 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
         fn get(&self, label: &str) -> Option<&Valve> {
             self.valves.get(&Valve::key(label))
         }
+
+        fn shortest_path(&self, from: Key, to: Key) -> Option<usize> {
+            dijkstra(from, |k| self.tunnels(k), |k| *k == to).map(|path| path.cost)
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = SAMPLE
+            .replacen("rate=13", "rate=x", 1)
+            .parse::<Network>()
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (2, "unrecognized valve"));
+        let lost = "Valve AA has flow rate=0; tunnels lead to valves BB, ZZ\n\
+                    Valve BB has flow rate=3; tunnel leads to valve AA";
+        let e = lost.parse::<Network>().err().unwrap();
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (1, "tunnel to unknown valve ZZ")
+        );
+        let apart = "Valve AA has flow rate=0; tunnel leads to valve AA\n\
+                     Valve BB has flow rate=3; tunnel leads to valve BB";
+        let e = apart.parse::<Network>().err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (1, "no path from AA to BB"));
     }
 
     #[test]
@@ -418,7 +409,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
 
     #[test]
     fn test_parse() {
-        let network: Network = Network::from(SAMPLE.lines());
+        let network: Network = SAMPLE.parse().unwrap();
         assert_eq!(network.valves.len(), 10);
         assert_eq!(network.get("AA").unwrap().rate, 0);
         assert_eq!(
@@ -443,7 +434,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
 
     #[test]
     fn test_execute_plan() {
-        let network: Network = Network::from(SAMPLE.lines());
+        let network: Network = SAMPLE.parse().unwrap();
         let mut actions = Vec::from([
             Action::m("DD"),
             Action::o("DD"),
@@ -482,7 +473,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
 
     #[test]
     fn test_shortcut_plan() {
-        let network: Network = Network::from(SAMPLE.lines());
+        let network: Network = SAMPLE.parse().unwrap();
         let mut actions = Vec::from([
             Action::o("DD"),
             Action::o("BB"),
@@ -507,7 +498,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
         let bb = Valve::key("BB");
         let jj = Valve::key("JJ");
         let hh = Valve::key("HH");
-        let network: Network = Network::from(SAMPLE.lines());
+        let network: Network = SAMPLE.parse().unwrap();
         assert_eq!(network.shortest_path(aa, aa).unwrap(), 0);
         assert_eq!(network.shortest_path(aa, bb).unwrap(), 1);
         assert_eq!(network.shortest_path(aa, jj).unwrap(), 2);
@@ -524,7 +515,7 @@ Valve JJ has flow rate=21; tunnel leads to valve II"#;
 
     #[test]
     fn test_optimal_plan() {
-        let network: Network = Network::from(SAMPLE.lines());
+        let network: Network = SAMPLE.parse().unwrap();
         println!("expecting DD, BB, JJ, HH, EE, CC, Wait");
         assert_eq!(network.optimal_plan("AA"), 1651);
     }
@@ -1,10 +1,11 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use aoc::math::{extrapolate_to, find_cycle_by};
+use aoc::{debug, ParseError, Point2, Screen, Solution};
+use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
-use std::fs;
-use vecmath::{vec2_add, Vector2};
+use std::str::FromStr;
 
 type Int = i64;
-type Coord = Vector2<Int>;
+type Coord = Point2<Int>;
 
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
 enum Move {
@@ -13,17 +14,28 @@ enum Move {
 }
 use Move::{Left, Right};
 
+#[derive(Clone)]
 struct Moves {
     q: VecDeque<Move>,
     n: usize,
 }
 
-impl From<&str> for Moves {
-    fn from(s: &str) -> Self {
-        Moves {
-            q: s.trim().chars().map(Move::from).collect::<VecDeque<Move>>(),
-            n: 0,
+impl FromStr for Moves {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let jets = s.trim();
+        let q = jets
+            .char_indices()
+            .map(|(i, c)| {
+                Move::try_from(c)
+                    .map_err(|_| ParseError::new("unknown move", s, &jets[i..i + c.len_utf8()]))
+            })
+            .collect::<Result<VecDeque<Move>, _>>()?;
+        if q.is_empty() {
+            return Err(ParseError::line("there are no moves", s));
         }
+        Ok(Moves { q, n: 0 })
     }
 }
 
@@ -57,39 +69,45 @@ struct Sprite {
 }
 use Piece::{Bar, Cross, Ell, Square, Stick};
 
+fn blocks(cells: &[(Int, Int)]) -> Vec<Coord> {
+    cells.iter().map(|&c| Coord::from(c)).collect()
+}
+
 impl Sprite {
     fn get(piece: &Piece) -> Self {
         match piece {
             Bar => Sprite {
                 w: 4,
-                blocks: vec![[0, 0], [1, 0], [2, 0], [3, 0]],
+                blocks: blocks(&[(0, 0), (1, 0), (2, 0), (3, 0)]),
             },
             Cross => Sprite {
                 w: 3,
-                blocks: vec![[0, 1], [1, 0], [1, 1], [1, 2], [2, 1]],
+                blocks: blocks(&[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]),
             },
             Ell => Sprite {
                 w: 3,
-                blocks: vec![[0, 0], [1, 0], [2, 0], [2, 1], [2, 2]],
+                blocks: blocks(&[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
             },
             Stick => Sprite {
                 w: 1,
-                blocks: vec![[0, 0], [0, 1], [0, 2], [0, 3]],
+                blocks: blocks(&[(0, 0), (0, 1), (0, 2), (0, 3)]),
             },
             Square => Sprite {
                 w: 2,
-                blocks: vec![[0, 0], [0, 1], [1, 0], [1, 1]],
+                blocks: blocks(&[(0, 0), (0, 1), (1, 0), (1, 1)]),
             },
         }
     }
 }
 
-impl From<char> for Move {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Move {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
         match c {
-            '<' => Left,
-            '>' => Right,
-            _ => panic!("unknkwon move {c}"),
+            '<' => Ok(Left),
+            '>' => Ok(Right),
+            _ => Err(c),
         }
     }
 }
@@ -104,7 +122,7 @@ fn next_piece(prev: &Piece) -> Piece {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Board {
     top: Int,
     n: Int,
@@ -114,26 +132,29 @@ struct Board {
     w: Int,
 }
 
+/// Draws the tower from the top down, the falling rock and all. With a
+/// precision, only that many rows from the top are drawn: `{board:.40}`.
 impl Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut top = self.top;
         let sprite = Sprite::get(&self.piece);
         let mut sprite_blocks = HashSet::new();
         for block in sprite.blocks.iter() {
-            let coord = vec2_add(self.pos, *block);
+            let coord = self.pos + *block;
             sprite_blocks.insert(coord);
-            top = top.max(coord[1]);
+            top = top.max(coord.y);
         }
         top += 1;
-        for i in 0..top {
+        let rows = f.precision().map_or(top, |rows| top.min(rows as Int));
+        for i in 0..rows {
             let y = top - i - 1;
             let mut row = String::with_capacity(self.w as usize + 2);
             row.push('|');
             for x in 0..self.w {
-                if sprite_blocks.contains(&[x, y]) {
+                if sprite_blocks.contains(&Coord::new(x, y)) {
                     row.push('@');
                 } else {
-                    row.push(match self.occupied(&[x, y]) {
+                    row.push(match self.occupied(&Coord::new(x, y)) {
                         true => '#',
                         false => '.',
                     });
@@ -142,8 +163,9 @@ impl Display for Board {
             row.push('|');
             writeln!(f, "{row}")?;
         }
-        let bottom = "-".repeat(self.w as usize + 2);
-        writeln!(f, "{bottom}")?;
+        let floor = if rows == top { "-" } else { "~" };
+        writeln!(f, "{}", floor.repeat(self.w as usize + 2))?;
+        writeln!(f, "rocks: {}", self.n)?;
         writeln!(f, "top: {}", self.top)?;
         writeln!(f, "piece: {:?}", self.piece)
     }
@@ -155,7 +177,7 @@ impl Board {
             top: 0,
             occupied: HashSet::new(),
             piece: Bar,
-            pos: [2, 3],
+            pos: Coord::new(2, 3),
             w: 7,
             n: 0,
         }
@@ -165,24 +187,24 @@ impl Board {
         let sprite = Sprite::get(&self.piece);
         match m {
             Left => {
-                if self.pos[0] > 0 && !self.collide(&sprite, &vec2_add(self.pos, [-1, 0])) {
-                    self.pos[0] -= 1;
+                if self.pos.x > 0 && !self.collide(&sprite, &(self.pos + Coord::new(-1, 0))) {
+                    self.pos.x -= 1;
                 }
             }
             Right => {
-                if (self.pos[0] + sprite.w) < self.w
-                    && !self.collide(&sprite, &vec2_add(self.pos, [1, 0]))
+                if (self.pos.x + sprite.w) < self.w
+                    && !self.collide(&sprite, &(self.pos + Coord::new(1, 0)))
                 {
-                    self.pos[0] += 1;
+                    self.pos.x += 1;
                 }
             }
         }
-        if self.pos[1] > 0 && !self.collide(&sprite, &vec2_add(self.pos, [0, -1])) {
-            self.pos = vec2_add(self.pos, [0, -1]);
+        if self.pos.y > 0 && !self.collide(&sprite, &(self.pos + Coord::new(0, -1))) {
+            self.pos += Coord::new(0, -1);
         } else {
             self.cement(&sprite);
             self.piece = next_piece(&self.piece);
-            self.pos = [2, self.top + 3];
+            self.pos = Coord::new(2, self.top + 3);
         }
     }
 
@@ -190,7 +212,7 @@ impl Board {
         sprite
             .blocks
             .iter()
-            .any(|block| self.occupied(&vec2_add(*pos, *block)))
+            .any(|block| self.occupied(&(*pos + *block)))
     }
 
     fn occupied(&self, p: &Coord) -> bool {
@@ -199,8 +221,8 @@ impl Board {
 
     fn cement(&mut self, sprite: &Sprite) {
         for block in sprite.blocks.iter() {
-            let p = vec2_add(self.pos, *block);
-            self.top = self.top.max(p[1] + 1);
+            let p = self.pos + *block;
+            self.top = self.top.max(p.y + 1);
             self.occupied.insert(p);
         }
         self.n += 1;
@@ -217,39 +239,35 @@ impl Board {
     fn clean(&mut self) {
         let mut defragged = HashSet::new();
         for cell in self.occupied.drain() {
-            if cell[1] > self.top - 100 {
+            if cell.y > self.top - 100 {
                 defragged.insert(cell);
             }
         }
         self.occupied = defragged;
     }
 
-    fn find_repeat(&mut self, moves: &mut Moves) -> (i64, i64) {
-        let mut memory = HashMap::new();
-        loop {
-            let fingerprint = Fingerprint::new(self, moves);
-            if let Some((rock, top)) = memory.get(&fingerprint) {
-                return (*rock, *top);
-            }
-            memory.insert(fingerprint, (self.n, self.top));
-            self.drop(moves);
-        }
+    /// How many rocks fall before the tower starts repeating itself, and how
+    /// many rocks fall in each repeat.
+    fn find_repeat(&self, moves: &Moves) -> (usize, usize) {
+        let step = |(board, moves): &(Board, Moves)| {
+            let (mut board, mut moves) = (board.clone(), moves.clone());
+            board.drop(&mut moves);
+            (board, moves)
+        };
+        find_cycle_by((self.clone(), moves.clone()), step, |(board, moves)| {
+            Fingerprint::new(board, moves)
+        })
     }
 
     fn power_drop(&mut self, moves: &mut Moves, goal: Int) -> Int {
-        let (preamble_n, preamble_top) = self.find_repeat(moves);
-        let loop_length = self.n - preamble_n;
-        println!("found a loop of {loop_length} starting at {preamble_n}");
-        let loop_gain = self.top - preamble_top;
-        let remaining = goal - preamble_n;
-        let loops = remaining / loop_length;
-        let loops_gain = loops * loop_gain;
-        let remaining = remaining % loop_length;
-        let remain_base = self.top;
-        for _ in 0..remaining {
+        let (preamble, loop_length) = self.find_repeat(moves);
+        debug!("found a loop of {loop_length} starting at {preamble}");
+        let mut tops = vec![self.top];
+        for _ in 0..preamble + loop_length {
             self.drop(moves);
+            tops.push(self.top);
         }
-        preamble_top + loops_gain + (self.top - remain_base)
+        extrapolate_to(&tops, (preamble, loop_length), goal as usize)
     }
 }
 
@@ -267,7 +285,7 @@ impl Fingerprint {
         let mut t = Vec::new();
         for i in 0..board.w {
             let mut j = board.top;
-            while !board.occupied(&[i, j]) && j >= 0 {
+            while !board.occupied(&Coord::new(i, j)) && j >= 0 {
                 j -= 1;
             }
             t.push(board.top - j);
@@ -276,42 +294,78 @@ impl Fingerprint {
     }
 }
 
-fn main() {
-    let input: &str = &fs::read_to_string("input/017.txt").expect("file read error");
-    let mut moves = Moves::from(input);
-    println!("there are {} moves", moves.q.len());
-    let mut board = Board::new();
-    for _ in 0..2022 {
-        board.drop(&mut moves);
+pub struct Day017 {
+    moves: Moves,
+}
+
+impl Solution for Day017 {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day017 {
+            moves: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> Int {
+        let mut moves = self.moves.clone();
+        let mut board = Board::new();
+        for _ in 0..2022 {
+            board.drop(&mut moves);
+        }
+        board.top
     }
-    println!("top of structure after 2022 blocks is {}", board.top);
 
-    let mut moves = Moves::from(input);
-    let mut board = Board::new();
-    let top = board.power_drop(&mut moves, 1_000_000_000_000_i64);
-    println!("top of structure after 1T blocks is {top}");
+    fn part2(&self) -> Int {
+        let mut moves = self.moves.clone();
+        let mut board = Board::new();
+        board.power_drop(&mut moves, 1_000_000_000_000_i64)
+    }
+
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool {
+        let mut moves = self.moves.clone();
+        let mut board = Board::new();
+        // part 2 only ever simulates as far as the first repeat
+        let rocks = match part {
+            1 => 2022,
+            _ => {
+                let (preamble, loop_length) = board.find_repeat(&moves);
+                (preamble + loop_length) as Int
+            }
+        };
+        let mut watching = screen.show(&format!("{board:.40}"));
+        while watching && board.n < rocks {
+            board.execute(moves.next());
+            watching = screen.show(&format!("{board:.40}"));
+        }
+        true
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
 "#;
 
     #[test]
     fn test_parse() {
-        let moves: VecDeque<Move> = SAMPLE.trim().chars().map(Move::from).collect();
+        let moves = Moves::from_str(SAMPLE).unwrap().q;
         assert_eq!(moves.len(), 40);
         assert_eq!(moves[0], Right);
         assert_eq!(moves[1], Right);
         assert_eq!(moves[2], Right);
         assert_eq!(moves[3], Left);
         assert_eq!(moves[39], Right);
+        let e = Moves::from_str(">><x<").err().unwrap();
+        assert_eq!((e.column, e.text.as_str()), (4, "x"));
+        assert!(Moves::from_str("\n").is_err());
     }
 
     #[test]
     fn test_next_move() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         assert_eq!(moves.next(), Right);
         assert_eq!(moves.next(), Right);
         assert_eq!(moves.next(), Right);
@@ -327,54 +381,54 @@ mod tests {
 
     #[test]
     fn test_move() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         // Bar, Cross, Ell, Stick, Square
         let mut board = Board::new();
         assert_eq!(board.piece, Bar);
-        assert_eq!(board.pos, [2, 3]);
+        assert_eq!(board.pos, Coord::new(2, 3));
 
         board.execute(moves.next()); // Right
         assert_eq!(board.piece, Bar);
-        assert_eq!(board.pos, [3, 2]);
+        assert_eq!(board.pos, Coord::new(3, 2));
 
         board.execute(moves.next()); // Right
         assert_eq!(board.piece, Bar);
-        assert_eq!(board.pos, [3, 1]);
+        assert_eq!(board.pos, Coord::new(3, 1));
 
         board.execute(moves.next()); // Right
         assert_eq!(board.piece, Bar);
-        assert_eq!(board.pos, [3, 0]);
+        assert_eq!(board.pos, Coord::new(3, 0));
 
         board.execute(moves.next()); // Left
-        assert!(!board.occupied(&[1, 0])); // cement the bar
-        assert!(!board.occupied(&[1, 1]));
-        assert!(board.occupied(&[2, 0]));
-        assert!(board.occupied(&[5, 0]));
-        assert!(!board.occupied(&[6, 0]));
+        assert!(!board.occupied(&Coord::new(1, 0))); // cement the bar
+        assert!(!board.occupied(&Coord::new(1, 1)));
+        assert!(board.occupied(&Coord::new(2, 0)));
+        assert!(board.occupied(&Coord::new(5, 0)));
+        assert!(!board.occupied(&Coord::new(6, 0)));
         assert_eq!(board.piece, Cross);
         assert_eq!(board.top, 1);
-        assert_eq!(board.pos, [2, 4]);
+        assert_eq!(board.pos, Coord::new(2, 4));
 
         board.execute(moves.next()); // Left
         assert_eq!(board.piece, Cross);
         assert_eq!(board.top, 1);
-        assert_eq!(board.pos, [1, 3]);
+        assert_eq!(board.pos, Coord::new(1, 3));
 
         board.execute(moves.next()); // Right
         assert_eq!(board.piece, Cross);
         assert_eq!(board.top, 1);
-        assert_eq!(board.pos, [2, 2]);
+        assert_eq!(board.pos, Coord::new(2, 2));
 
         board.execute(moves.next()); // Left
         assert_eq!(board.piece, Cross);
         assert_eq!(board.top, 1);
-        assert_eq!(board.pos, [1, 1]);
+        assert_eq!(board.pos, Coord::new(1, 1));
 
         board.execute(moves.next()); // Right
-        assert!(board.occupied(&[3, 3])); // cement the cross
+        assert!(board.occupied(&Coord::new(3, 3))); // cement the cross
         assert_eq!(board.piece, Ell);
         assert_eq!(board.top, 4);
-        assert_eq!(board.pos, [2, 7]);
+        assert_eq!(board.pos, Coord::new(2, 7));
 
         while board.piece == Ell {
             board.execute(moves.next());
@@ -401,7 +455,7 @@ mod tests {
 
     #[test]
     fn test_drop() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         let mut board = Board::new();
         for _ in 0..10 {
             board.drop(&mut moves);
@@ -412,7 +466,7 @@ mod tests {
 
     #[test]
     fn test_drop_all_the_things() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         let mut board = Board::new();
         for _ in 0..2022 {
             board.drop(&mut moves);
@@ -423,7 +477,7 @@ mod tests {
 
     #[test]
     fn test_fingerprint() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         let mut board = Board::new();
         let mut memory = HashSet::new();
         memory.insert(Fingerprint::new(&board, &moves));
@@ -451,18 +505,38 @@ mod tests {
 
     #[test]
     fn test_find_loops() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         let mut board = Board::new();
-        assert_eq!(board.find_repeat(&mut moves), (28, 49));
-        assert_eq!(board.n, 63);
+        assert_eq!(board.find_repeat(&moves), (28, 35));
+        for _ in 0..28 {
+            board.drop(&mut moves);
+        }
+        assert_eq!(board.top, 49);
+        for _ in 28..63 {
+            board.drop(&mut moves);
+        }
         assert_eq!(board.top, 102);
     }
 
     #[test]
     fn test_terra_drop() {
-        let mut moves = Moves::from(SAMPLE);
+        let mut moves = Moves::from_str(SAMPLE).unwrap();
         let mut board = Board::new();
         let top = board.power_drop(&mut moves, 1_000_000_000_000_i64);
         assert_eq!(top, 1_514_285_714_288_i64);
     }
+
+    #[test]
+    fn test_visualize() {
+        let day = Day017::parse(SAMPLE).unwrap();
+        let mut frames = Vec::new();
+        assert!(day.visualize(2, &mut frames));
+        assert_eq!(
+            frames[0],
+            "|..@@@@.|\n|.......|\n|.......|\n|.......|\n---------\nrocks: 0\ntop: 0\npiece: Bar\n"
+        );
+        let last = frames.last().unwrap();
+        assert!(last.contains("~~~~~~~~~\nrocks: 63\ntop: 102\n"));
+        assert_eq!(last.lines().count(), 40 + 4);
+    }
 }
@@ -0,0 +1,393 @@
+use aoc::image::{slices, Image, BLACK, BLUE, ORANGE};
+use aoc::{parse_lines, ParseError, Point3, Solution};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+type Int = i8;
+type Key = u64;
+type Coord = Point3<Int>;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Cube {
+    pos: Coord,
+    key: Key,
+}
+
+#[derive(Clone)]
+struct Cubes {
+    store: HashMap<Key, Cube>,
+    known_outside: HashSet<Key>,
+    lb: Coord,
+    ub: Coord,
+}
+impl Cube {
+    fn key(c: &Coord) -> Key {
+        ((c.x as Key) << 16) + ((c.y as Key) << 8) + (c.z as Key)
+    }
+
+    fn valid(c: &Coord) -> bool {
+        c.x >= 0 && c.y >= 0 && c.z >= 0
+    }
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        // keys only tell cubes apart in the positive octant
+        let coords = line
+            .split(',')
+            .map(|n| match n.parse::<Int>() {
+                Ok(v) if v >= 0 => Ok(v),
+                _ => Err(ParseError::new("coordinate is not 0 to 127", line, n)),
+            })
+            .collect::<Result<Vec<Int>, _>>()?;
+        let [x, y, z] = coords[..] else {
+            return Err(ParseError::line("cube is not x,y,z", line));
+        };
+        Ok(Cube::new(Coord::new(x, y, z)))
+    }
+}
+
+impl Cube {
+    fn new(pos: Coord) -> Self {
+        Cube {
+            pos,
+            key: Self::key(&pos),
+        }
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let store: HashMap<Key, Cube> = parse_lines::<Cube>(input)?
+            .into_iter()
+            .map(|c| (c.key, c))
+            .collect();
+        let mut ub = Coord::new(0, 0, 0);
+        let mut lb = Coord::new(Int::MAX, Int::MAX, Int::MAX);
+        for (_, cube) in store.iter() {
+            lb = lb.min_each(&cube.pos);
+            ub = ub.max_each(&cube.pos);
+        }
+        Ok(Cubes {
+            store,
+            lb,
+            ub,
+            known_outside: HashSet::new(),
+        })
+    }
+}
+
+impl Cubes {
+    fn insert(&mut self, c: Cube) {
+        self.store.insert(c.key, c);
+    }
+
+    fn contains(&self, c: &Coord) -> bool {
+        self.store.contains_key(&Cube::key(c))
+    }
+
+    fn count_exposed(&self) -> usize {
+        let mut total = 0;
+        for (_, cube) in self.store.iter() {
+            for probe in cube.pos.neighbours6() {
+                if !Cube::valid(&probe) || !self.store.contains_key(&Cube::key(&probe)) {
+                    total += 1;
+                }
+            }
+        }
+        total
+    }
+
+    fn fill_holes(&mut self) {
+        let mut found_hole = true;
+        while found_hole {
+            found_hole = false;
+            'outer: for x in self.lb.x..self.ub.x {
+                for y in self.lb.y..self.ub.y {
+                    for z in self.lb.z..self.ub.z {
+                        let probe = Cube::new(Coord::new(x, y, z));
+                        if !self.contains(&probe.pos) {
+                            if self.trivially_outside(&probe.pos) {
+                                self.known_outside.insert(probe.key);
+                            } else {
+                                match self.flood(&probe) {
+                                    Some(void) => {
+                                        for q in void {
+                                            self.insert(q);
+                                        }
+                                        found_hole = true;
+                                        break 'outer;
+                                    }
+                                    None => self.known_outside.insert(probe.key),
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn flood(&self, seed: &Cube) -> Option<Vec<Cube>> {
+        if self.contains(&seed.pos) {
+            return None;
+        }
+        if self.trivially_outside(&seed.pos) {
+            return None;
+        }
+        let mut void = Vec::new();
+        void.push(seed.clone());
+        let mut frontier = Vec::new();
+        frontier.push(seed.clone());
+        loop {
+            let mut found = false;
+            let mut candidates = Vec::new();
+            candidates.append(&mut frontier);
+            for candidate in candidates.drain(..) {
+                for neighbour in candidate.pos.neighbours6() {
+                    let probe = Cube::new(neighbour);
+                    if !self.store.contains_key(&probe.key) && !void.contains(&probe) {
+                        if self.trivially_outside(&probe.pos) {
+                            return None;
+                        } else {
+                            frontier.push(probe.clone());
+                            void.push(probe);
+                            found = true;
+                        }
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+        }
+        Some(void)
+    }
+
+    fn trivially_outside(&self, z: &Coord) -> bool {
+        // trivially outside
+        if self.known_outside.contains(&Cube::key(z)) {
+            return true;
+        }
+        if z.x <= self.lb.x || z.y <= self.lb.y || z.z <= self.lb.z {
+            return true;
+        }
+        if z.x >= self.ub.x || z.y >= self.ub.y || z.z >= self.ub.z {
+            return true;
+        }
+        for dim in 0..3 {
+            let mut delta = Coord::default();
+            delta[dim] = -1;
+            let mut probe = *z + delta;
+            let mut clear_down = true;
+            while probe[dim] >= self.lb[dim] {
+                if self.contains(&probe) {
+                    clear_down = false;
+                }
+                probe += delta;
+            }
+            delta[dim] = 1;
+            let mut probe = *z + delta;
+            let mut clear_up = true;
+            while probe[dim] <= self.ub[dim] {
+                if self.contains(&probe) {
+                    clear_up = false;
+                }
+                probe += delta;
+            }
+            if clear_up || clear_down {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The droplet a slice at a time from the bottom up, lava in orange and
+/// the air trapped inside it in blue.
+fn picture(cubes: &Cubes) -> Image {
+    let mut filled = cubes.clone();
+    filled.fill_holes();
+    let widen = |c: Coord| Point3::new(c.x as i32, c.y as i32, c.z as i32);
+    slices(widen(cubes.lb), widen(cubes.ub), |p| {
+        let c = Coord::new(p.x as Int, p.y as Int, p.z as Int);
+        if cubes.contains(&c) {
+            ORANGE
+        } else if filled.contains(&c) {
+            BLUE
+        } else {
+            BLACK
+        }
+    })
+    .scale(4)
+}
+
+pub struct Day018 {
+    cubes: Cubes,
+}
+
+impl Solution for Day018 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day018 {
+            cubes: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.cubes.count_exposed()
+    }
+
+    fn part2(&self) -> usize {
+        let mut cubes = self.cubes.clone();
+        cubes.fill_holes();
+        cubes.count_exposed()
+    }
+
+    fn picture(&self) -> Option<Image> {
+        Some(picture(&self.cubes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5"#;
+
+    impl Cubes {
+        fn len(&self) -> usize {
+            self.store.len()
+        }
+    }
+
+    #[test]
+    fn test_parse_cube() {
+        let cubes: Vec<Cube> = parse_lines(SAMPLE).unwrap();
+        assert_eq!(cubes.len(), 13);
+        assert_eq!(cubes[0].pos, Coord::new(2, 2, 2));
+        let e = parse_lines::<Cube>("2,2,2\n1,-2,2").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "-2"));
+        let e = parse_lines::<Cube>("2,2,2\n1,2").err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (2, "cube is not x,y,z"));
+        assert!(Cubes::from_str("1,2,300").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert_eq!(cubes.len(), 13);
+        assert!(cubes.contains(&Coord::new(2, 2, 2)));
+        assert!(cubes.contains(&Coord::new(2, 3, 5)));
+        assert!(!cubes.contains(&Coord::new(2, 3, 6)));
+    }
+
+    #[test]
+    fn test_count_exposed() {
+        let cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert_eq!(cubes.count_exposed(), 64);
+    }
+
+    #[test]
+    fn test_cubes_bounds() {
+        let cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert_eq!(cubes.lb, Coord::new(1, 1, 1));
+        assert_eq!(cubes.ub, Coord::new(3, 3, 6));
+    }
+
+    #[test]
+    fn test_cubes_outside() {
+        let cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert!(cubes.trivially_outside(&Coord::new(1, 1, 1)));
+        assert!(!cubes.trivially_outside(&Coord::new(2, 2, 5)));
+        assert!(cubes.trivially_outside(&Coord::new(2, 4, 5)));
+    }
+
+    #[test]
+    fn test_cubes_flood() {
+        let cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert!(cubes.flood(&Cube::new(Coord::new(1, 1, 1))).is_none());
+        assert!(cubes.flood(&Cube::new(Coord::new(2, 4, 5))).is_none());
+        assert!(cubes.flood(&Cube::new(Coord::new(2, 2, 5))).is_some());
+        assert_eq!(
+            cubes.flood(&Cube::new(Coord::new(2, 2, 5))).unwrap(),
+            [Cube::new(Coord::new(2, 2, 5))]
+        );
+    }
+
+    const BIG_VOID: &str = r#"1,1,1
+2,1,2
+1,2,2
+2,2,1
+3,2,2
+2,2,3
+1,3,2
+2,3,1
+2,4,2
+3,3,2
+2,3,3"#;
+
+    #[test]
+    fn test_cubes_big_flood() {
+        let cubes: Cubes = Cubes::from_str(BIG_VOID).unwrap();
+        let res = cubes.flood(&Cube::new(Coord::new(2, 2, 2)));
+        assert!(res.is_some());
+        let res = res.unwrap();
+        assert_eq!(res.len(), 2);
+        assert!(res.contains(&Cube::new(Coord::new(2, 2, 2))));
+        assert!(res.contains(&Cube::new(Coord::new(2, 3, 2))));
+    }
+
+    #[test]
+    fn test_cubes_fill_holes() {
+        let mut cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        assert!(
+            !cubes.contains(&Coord::new(2, 2, 5)),
+            "this should be a hole"
+        );
+        cubes.fill_holes();
+        assert!(
+            cubes.contains(&Coord::new(2, 2, 5)),
+            "this hole should be filled"
+        );
+    }
+
+    #[test]
+    fn test_count_exposed_ignore_voids() {
+        let mut cubes: Cubes = Cubes::from_str(SAMPLE).unwrap();
+        cubes.fill_holes();
+        assert_eq!(cubes.count_exposed(), 58);
+    }
+
+    #[test]
+    fn test_picture() {
+        let image = picture(&Cubes::from_str(SAMPLE).unwrap());
+        // six slices of three by three, four pixels to a place
+        assert_eq!((image.width(), image.height()), (4 * 23, 4 * 3));
+        assert_eq!(image.get((4 * 5, 4)), Some(ORANGE));
+        assert_eq!(image.get((4 * 3, 0)), Some(aoc::image::GREY));
+        // the one air pocket is in the middle of the fifth slice
+        assert_eq!(image.get((4 * 17 + 1, 5)), Some(BLUE));
+        assert_eq!(image.get((4 * 16, 4)), Some(ORANGE));
+        assert_eq!(image.get((0, 0)), Some(BLACK));
+        let empty = picture(&Cubes::from_str("").unwrap());
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+}
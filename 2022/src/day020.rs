@@ -1,4 +1,4 @@
-use std::fs;
+use aoc::{ParseError, Solution};
 const KEY: i128 = 811_589_153;
 
 #[derive(Debug)]
@@ -64,7 +64,7 @@ fn move_all(ring: &mut Vec<Node>) {
         move_node(ring, i);
     }
 }
-fn score(ring: &Vec<Node>) -> i128 {
+fn score(ring: &[Node]) -> i128 {
     let zero = find_value(ring, 0).expect("no node with value 0") as i128;
     let n = ring.len() as i128;
     let a = wrap(zero + 1000, n) as usize;
@@ -79,28 +79,42 @@ fn decrypt(ring: &mut [Node], key: i128) {
     }
 }
 
-fn main() {
-    let input: &str = &fs::read_to_string("input/020.txt").expect("file read error");
-    let numbers = input
-        .lines()
-        .map(|s| s.parse::<i128>().expect("not a number"))
-        .collect::<Vec<i128>>();
-    let mut ring = string_nodes(numbers.clone());
-    println!("there are {} nodes", ring.len());
-    move_all(&mut ring);
-    println!("corrupted grove coordinate is {}", score(&ring));
-
-    ring = string_nodes(numbers);
-    decrypt(&mut ring, KEY);
-    for _ in 0..10 {
+pub struct Day020 {
+    numbers: Vec<i128>,
+}
+
+impl Solution for Day020 {
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day020 {
+            numbers: input
+                .lines()
+                .map(|s| s.parse::<i128>().expect("not a number"))
+                .collect(),
+        })
+    }
+
+    fn part1(&self) -> i128 {
+        let mut ring = string_nodes(self.numbers.clone());
         move_all(&mut ring);
+        score(&ring)
+    }
+
+    fn part2(&self) -> i128 {
+        let mut ring = string_nodes(self.numbers.clone());
+        decrypt(&mut ring, KEY);
+        for _ in 0..10 {
+            move_all(&mut ring);
+        }
+        score(&ring)
     }
-    println!("actual grove coordiante is {}", score(&ring));
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"1
 2
 -3
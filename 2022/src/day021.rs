@@ -1,15 +1,15 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+use aoc::{debug, parse_line, parse_lines, trace, ParseError, Solution};
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::fs;
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
-lazy_static! {
-    static ref NUMBER_MOKEY: regex::Regex = Regex::new(r"^([a-z]+): (\d+)$").unwrap();
-    static ref OPERATOR_MOKEY: regex::Regex =
-        Regex::new(r"^([a-z]+): ([a-z]+) (.) ([a-z]+)$").unwrap();
+parse_line! {
+    enum Job {
+        Number { name: String, number: i64 } = "{name}: {number}",
+        Operation { name: String, a: String, op: char, b: String } = "{name}: {a} {op} {b}",
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -95,11 +95,11 @@ impl Monkey {
         let mut lower_value = 1;
         Monkey::fix_human(troop, lower_value);
         let lower_order = Monkey::equality(troop);
-        println!("lower order is {:?}", lower_order);
+        debug!("lower order is {:?}", lower_order);
 
         let mut upper_value = 2;
         loop {
-            println!("searching for upper: {upper_value}");
+            trace!("searching for upper: {upper_value}");
             Monkey::fix_human(troop, upper_value);
             if lower_order != Monkey::equality(troop) {
                 break;
@@ -109,7 +109,7 @@ impl Monkey {
 
         loop {
             let probe = lower_value + (upper_value - lower_value) / 2;
-            println!("testing {probe}");
+            trace!("testing {probe}");
             Monkey::fix_human(troop, probe);
             let result = Monkey::equality(troop);
             if result == Ordering::Equal {
@@ -124,53 +124,60 @@ impl Monkey {
     }
 }
 
-impl From<&str> for Monkey {
-    fn from(s: &str) -> Self {
-        match NUMBER_MOKEY.captures(s) {
-            Some(cap) => {
-                // number monkey
-                let name = cap.get(1).expect("missing name").as_str();
-                let operand = cap
-                    .get(2)
-                    .expect("missing number")
-                    .as_str()
-                    .parse::<i64>()
-                    .expect("not a number");
-                Monkey::new(name, Yell(operand))
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s.parse()? {
+            Job::Number { name, number } => Ok(Monkey::new(&name, Yell(number))),
+            Job::Operation { name, a, op, b } => {
+                let (a, b) = (Monkey::name_to_id(&a), Monkey::name_to_id(&b));
+                let op = match op {
+                    '+' => Add(a, b),
+                    '-' => Sub(a, b),
+                    '*' => Mul(a, b),
+                    '/' => Div(a, b),
+                    _ => return Err(ParseError::line(format!("unknown operator `{op}`"), s)),
+                };
+                Ok(Monkey::new(&name, op))
             }
-            None => match OPERATOR_MOKEY.captures(s) {
-                // operator monkey
-                Some(cap) => {
-                    let name = cap.get(1).expect("missing name").as_str();
-                    let a = Monkey::name_to_id(cap.get(2).expect("missing operand a").as_str());
-                    let op = cap.get(3).expect("missing operand a").as_str();
-                    let b = Monkey::name_to_id(cap.get(4).expect("missing operand b").as_str());
-                    let op = match op {
-                        "+" => Add(a, b),
-                        "-" => Sub(a, b),
-                        "*" => Mul(a, b),
-                        "/" => Div(a, b),
-                        _ => panic!("unknown operaotr"),
-                    };
-                    Monkey::new(name, op)
-                }
-                None => panic!("unparsable Monkey {s}"),
-            },
         }
     }
 }
 
-fn main() {
-    let input: &str = &fs::read_to_string("input/021.txt").expect("file read error");
-    let mut troop: Troop = input.lines().map(Monkey::from).map(|m| (m.id, m)).collect();
-    println!("there are {} monkeys", troop.len());
-    println!("the troop will yell {}", Monkey::evaluate(&troop));
-    println!("human yells {}", Monkey::find_equality(&mut troop));
+fn parse_troop(input: &str) -> Result<Troop, ParseError> {
+    Ok(parse_lines::<Monkey>(input)?
+        .into_iter()
+        .map(|m| (m.id, m))
+        .collect())
+}
+
+pub struct Day021 {
+    troop: Troop,
+}
+
+impl Solution for Day021 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day021 {
+            troop: parse_troop(input)?,
+        })
+    }
+
+    fn part1(&self) -> i64 {
+        Monkey::evaluate(&self.troop)
+    }
+
+    fn part2(&self) -> i64 {
+        Monkey::find_equality(&mut self.troop.clone())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"root: pppw + sjmn
 dbpl: 5
 cczh: sllz + lgvd
@@ -189,11 +196,7 @@ hmdt: 32"#;
 
     #[test]
     fn test_parse_troop() {
-        let troop: Troop = SAMPLE
-            .lines()
-            .map(Monkey::from)
-            .map(|m| (m.id, m))
-            .collect();
+        let troop: Troop = parse_troop(SAMPLE).unwrap();
         assert_eq!(troop.len(), 15);
         assert!(Monkey::find(&troop, "root").is_some());
         assert_eq!(
@@ -201,25 +204,21 @@ hmdt: 32"#;
             Add(Monkey::name_to_id("pppw"), Monkey::name_to_id("sjmn"))
         );
         assert_eq!(Monkey::find(&troop, "sllz").unwrap().op, Yell(4));
+        let e = parse_troop("root: pppw % sjmn").unwrap_err();
+        assert_eq!(e.reason, "unknown operator `%`");
+        let e = parse_troop("dbpl: 5\ndbpl: five").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 7));
     }
 
     #[test]
     fn test_execute() {
-        let troop: Troop = SAMPLE
-            .lines()
-            .map(Monkey::from)
-            .map(|m| (m.id, m))
-            .collect();
+        let troop: Troop = parse_troop(SAMPLE).unwrap();
         assert_eq!(Monkey::evaluate(&troop), 152);
     }
 
     #[test]
     fn test_equality() {
-        let mut troop: Troop = SAMPLE
-            .lines()
-            .map(Monkey::from)
-            .map(|m| (m.id, m))
-            .collect();
+        let mut troop: Troop = parse_troop(SAMPLE).unwrap();
         Monkey::fix_root(&mut troop);
         Monkey::fix_human(&mut troop, 301);
         assert_eq!(Monkey::equality(&troop), Ordering::Equal);
@@ -229,11 +228,7 @@ hmdt: 32"#;
 
     #[test]
     fn find_equality() {
-        let mut troop: Troop = SAMPLE
-            .lines()
-            .map(Monkey::from)
-            .map(|m| (m.id, m))
-            .collect();
+        let mut troop: Troop = parse_troop(SAMPLE).unwrap();
         assert!([301, 302].contains(&Monkey::find_equality(&mut troop)));
     }
 }
@@ -1,16 +1,14 @@
-use lazy_static::lazy_static;
-use std::{fs, str::Lines, fmt};
+use aoc::{Direction, ParseError, Point2, Screen, Solution, Unsolved};
+use std::{str::Lines, fmt};
 use std::collections::HashMap;
-use vecmath::{vec2_add, vec2_neg, Vector2};
 use pest::{Parser, iterators::Pair};
-#[macro_use]
-extern crate pest_derive;
+use pest_derive::Parser;
 
 #[derive(Parser)]
-#[grammar = "bin/022.pest"]
+#[grammar = "day022.pest"]
 struct ListParser;
 
-type Coord = Vector2<i32>;
+type Coord = Point2<i32>;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum Cell {
@@ -48,11 +46,11 @@ impl Map {
     }
 
     fn get(&self, coord: &Coord) -> Cell {
-        if coord[1] < 0 || coord[0] < 0 { 
+        if coord.y < 0 || coord.x < 0 { 
             return Void;
         }
-        let i = coord[0] as usize;
-        let j = coord[1] as usize;
+        let i = coord.x as usize;
+        let j = coord.y as usize;
         if j >= self.m.len() {
             return Void;
         }
@@ -63,21 +61,21 @@ impl Map {
     }
 
     fn find_start(&self) -> State {
-        let mut x = [0, 0];
+        let mut x = Coord::new(0, 0);
         let f = East;
         for (i, c) in self.m[0].iter().enumerate() {
             if *c == Open {
-                x[0] = i as i32;
+                x.x = i as i32;
                 return State {x, f};
             }
         }
         panic!("couldn't find an open spot on the top row!");
     }
 
-    fn warp(&self, x: &Coord, dx: &Coord, wormholes: &Option<Edgemap>) -> Coord {
+    fn warp(&self, x: &Coord, dx: &Coord, _wormholes: &Option<Edgemap>) -> Coord {
         let mut x1 = *x;
         loop {
-            let x2 = vec2_add(x1, *dx);
+            let x2 = x1 + *dx;
             if self.get(&x2) == Void {
                 return x1;
             }
@@ -86,19 +84,32 @@ impl Map {
     }
 }
 
-impl fmt::Debug for Map {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in self.m.iter() {
-            for cell in row.iter() {
-                match cell {
-                    Void => write!(f, " "),
-                    Open => write!(f, "."),
-                    Wall => write!(f, "#"),
-                }?;
+impl Map {
+    /// The map with the way we came drawn on it, each place showing the way
+    /// we were facing when we last left it.
+    fn draw(&self, trail: &HashMap<Coord, Direction>) -> String {
+        let mut picture = String::new();
+        for (j, row) in self.m.iter().enumerate() {
+            for (i, cell) in row.iter().enumerate() {
+                picture.push(match (trail.get(&Coord::new(i as i32, j as i32)), cell) {
+                    (Some(East), _) => '>',
+                    (Some(South), _) => 'v',
+                    (Some(West), _) => '<',
+                    (Some(North), _) => '^',
+                    (None, Void) => ' ',
+                    (None, Open) => '.',
+                    (None, Wall) => '#',
+                });
             }
-            write!(f, "\n")?;
+            picture.push('\n');
         }
-        write!(f, "\n")
+        picture
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.draw(&HashMap::new()))
     }
 }
 
@@ -114,7 +125,7 @@ type Instructions = Vec<Instruction>;
 impl From<Pair<'_, Rule>> for Instruction {
     fn from(token: Pair<Rule>) -> Self {
         match token.as_rule() {
-            Rule::number => return Move(token.as_str().parse().expect("parser says so")),
+            Rule::number => Move(token.as_str().parse().expect("parser says so")),
             Rule::left => Left, 
             Rule::right => Right, 
             Rule::list => panic!("no recursive lists!"), 
@@ -133,19 +144,12 @@ fn parse_all(input: &str) -> (Map, Instructions) {
     (Map::read(&mut lines), parse_instructions(lines.next().unwrap()))
 }
 
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-enum Facing { 
-    North,
-    South,
-    East,
-    West,
-}
-use Facing::{North, South, East, West};
+use Direction::{North, South, East, West};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 struct State {
     x: Coord,
-    f: Facing,
+    f: Direction,
 }
 
 impl State {
@@ -158,8 +162,8 @@ impl State {
             West => 2,
             North => 3,
         };
-        let column_score = 4 * (self.x[0] + 1);
-        let row_score = 1000 * (self.x[1] + 1);
+        let column_score = 4 * (self.x.x + 1);
+        let row_score = 1000 * (self.x.y + 1);
         facing_score + column_score + row_score
 
     }
@@ -171,26 +175,21 @@ impl State {
 
     fn follow(&mut self, map: &Map, i: &Instruction, wormholes: &Option<Edgemap>) {
         match i {
-            Right => self.turn_right(),
-            Left =>  self.turn_left(),
+            Right => self.f = self.f.turn_right(),
+            Left =>  self.f = self.f.turn_left(),
             Move(n) => self.walk_forward(map, *n, wormholes),
         }
     }
 
     fn walk_forward(&mut self, map: &Map, n: i32, wormholes: &Option<Edgemap>) {
-        let dx = match self.f {
-            North => [0, -1],
-            East => [1, 0],
-            South => [0 ,1],
-            West => [-1, 0],
-        };
+        let dx = self.f.offset();
         for _ in 0..n {
-            let x1 = vec2_add(self.x, dx);
+            let x1 = self.x + dx;
             match map.get(&x1) {
                 Open => self.x = x1,
                 Wall => break,
                 Void => {
-                    let x1 = map.warp(&self.x, &vec2_neg(dx), wormholes);
+                    let x1 = map.warp(&self.x, &-dx, wormholes);
                     if map.get(&x1) == Wall {
                         break;
                     }
@@ -199,64 +198,76 @@ impl State {
             }
         }
     }
-
-    fn turn_left(&mut self) {
-        self.f = match self.f {
-            North => West,
-            West => South,
-            South => East,
-            East => North,
-        }
-    }
-
-    fn turn_right(&mut self) {
-        self.f = match self.f {
-            North => East,
-            East => South,
-            South => West,
-            West => North,
-        }
-    }
 }
 
 
-type Warp = (i32, i32, Facing);
+type Warp = (i32, i32, Direction);
 
+// part 2 folds the map into a cube, which isn't wired up yet
+#[allow(dead_code)]
 #[derive(Clone)]
 struct Edgemap {
     n: i32,
     j: HashMap<Warp, Warp>,
 }
 
-fn main() {
-    let input: &str = &fs::read_to_string("input/022.txt").expect("file read error");
-    let (map, instructions) = parse_all(input);
-    let mut state = map.find_start();
-    state.go_walkies(&map, &instructions, &None);
-    println!("the walk score is {}", state.score());
+pub struct Day022 {
+    map: Map,
+    instructions: Instructions,
+}
+
+impl Solution for Day022 {
+    type Part1 = i32;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, instructions) = parse_all(input);
+        Ok(Day022 { map, instructions })
+    }
+
+    fn part1(&self) -> i32 {
+        let mut state = self.map.find_start();
+        state.go_walkies(&self.map, &self.instructions, &None);
+        state.score()
+    }
+
+    fn part2(&self) -> Unsolved {
+        Unsolved
+    }
+
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool {
+        if part != 1 {
+            return false;
+        }
+        let mut state = self.map.find_start();
+        let mut trail = HashMap::from([(state.x, state.f)]);
+        for i in self.instructions.iter() {
+            // a step at a time, so the trail has every place on it
+            let steps = match i {
+                Move(n) => vec![&Move(1); *n as usize],
+                turn => vec![turn],
+            };
+            for step in steps {
+                state.follow(&self.map, step, &None);
+                trail.insert(state.x, state.f);
+            }
+            if !screen.show(&format!("{}{:?} facing {:?}\n", self.map.draw(&trail), i, state.f)) {
+                break;
+            }
+        }
+        true
+    }
 }
 #[test]
 fn test_score() {
-    assert_eq!(State { x: [7, 5], f: East}.score(), 6032);
+    assert_eq!(State { x: Coord::new(7, 5), f: East}.score(), 6032);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
-    const SAMPLE: &str = r#"        ...#
-        .#..
-        #...
-        ....
-...#.......#
-........#...
-..#....#....
-..........#.
-        ...#....
-        .....#..
-        .#......
-        ......#.
-
-10R5L5R10L4R5L5"#;
+    use super::*;
+    use lazy_static::lazy_static;
+    const SAMPLE: &str = include_str!("../input/022-sample-1.txt");
 
     lazy_static! {
         static ref sample_edgemap: Edgemap = Edgemap {
@@ -301,14 +312,14 @@ mod tests {
     fn test_parse_all() {
         let (map, instructions) = parse_all(SAMPLE);
         assert_eq!(instructions.len(), 13);
-        assert_eq!(map.get(&[11, 3]), Open);
-        assert_eq!(map.get(&[11, 4]), Wall);
-        assert_eq!(map.get(&[4, 8]), Void);
+        assert_eq!(map.get(&Coord::new(11, 3)), Open);
+        assert_eq!(map.get(&Coord::new(11, 4)), Wall);
+        assert_eq!(map.get(&Coord::new(4, 8)), Void);
     }
     #[test]
     fn test_find_start() {
         let (map, _) = parse_all(SAMPLE);
-        assert_eq!(map.find_start(), State { x: [8, 0], f: East});
+        assert_eq!(map.find_start(), State { x: Coord::new(8, 0), f: East});
 
     }
     #[test]
@@ -316,10 +327,24 @@ mod tests {
         let (map, instructions) = parse_all(SAMPLE);
         let mut state = map.find_start();
         state.go_walkies(&map, &instructions, &None);
-        assert_eq!(state, State { x: [7, 5], f: East});
+        assert_eq!(state, State { x: Coord::new(7, 5), f: East});
     }
     #[test]
     fn test_score() {
-        assert_eq!(State { x: [7, 5], f: East}.score(), 6032);
+        assert_eq!(State { x: Coord::new(7, 5), f: East}.score(), 6032);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let day = Day022::parse(SAMPLE).unwrap();
+        let mut frames = Vec::new();
+        assert!(day.visualize(1, &mut frames));
+        assert_eq!(frames.len(), 13);
+        let last: Vec<&str> = frames[12].lines().collect();
+        assert_eq!(last[0], "        >>v#");
+        assert_eq!(last[5], ">>>v...>#.>>");
+        assert_eq!(last[7], "...>>>>v..#.");
+        assert_eq!(last[12], "Move(5) facing East");
+        assert!(!day.visualize(2, &mut frames));
     }
 }
@@ -0,0 +1,229 @@
+use aoc::{Automaton, Direction, Direction8, Neighbourhood, ParseError, Point2, Screen, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use Direction::{East, North, South, West};
+
+type Coord = Point2<i32>;
+
+#[derive(Clone)]
+struct Map {
+    elves: Automaton<HashSet<Coord>>,
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ll, ur) = self.rect();
+        let ll = ll - Coord::new(2, 2);
+        let ur = ur + Coord::new(2, 2);
+        for j in ll.y..ur.y {
+            for i in ll.x..ur.x {
+                match self.elves.board.contains(&Coord::new(i, j)) {
+                    false => write!(f, "."),
+                    true => write!(f, "#"),
+                }?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{:?}", self.bound())
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut m = HashSet::new();
+        for (j, line) in input.lines().enumerate() {
+            for (i, (at, c)) in line.char_indices().enumerate() {
+                match c {
+                    '.' => (),
+                    '#' => {
+                        m.insert(Coord::new(i as i32, j as i32));
+                    }
+                    _ => {
+                        let bad = &line[at..at + c.len_utf8()];
+                        return Err(ParseError::new("unexpected map element", line, bad).below(j));
+                    }
+                };
+            }
+        }
+        Ok(Map {
+            elves: Automaton::new(m),
+        })
+    }
+}
+
+/// The directions elves consider moving in, which rotate every round.
+fn options(round: usize) -> [Direction; 4] {
+    let mut options = [North, South, West, East];
+    options.rotate_left(round % 4);
+    options
+}
+
+/// Where an elf proposes to go, if anywhere.
+fn spread(elf: &Neighbourhood<HashSet<Coord>>) -> Option<Coord> {
+    elf.neighbours8().next()?; // happy on their own, so don't move
+    options(elf.steps)
+        .into_iter()
+        .map(Direction8::from)
+        .find(|ahead| {
+            [*ahead, ahead.turn_left(), ahead.turn_right()]
+                .iter()
+                .all(|d| elf.look(*d).is_none())
+        })
+        .map(|ahead| elf.at + ahead.offset())
+}
+
+impl Map {
+    fn tick(&mut self) -> bool {
+        self.elves.propose(spread)
+    }
+
+    fn rect(&self) -> (Coord, Coord) {
+        let mut ll = Coord::new(i32::MAX, i32::MAX);
+        let mut ur = Coord::new(0, 0);
+        for elf in self.elves.board.iter() {
+            ll = ll.min_each(elf);
+            ur = ur.max_each(elf);
+        }
+        (ll, ur + Coord::new(1, 1))
+    }
+
+    fn bound(&self) -> Coord {
+        let (ll, ur) = self.rect();
+        ur - ll
+    }
+    fn empty_ground(&self) -> u32 {
+        let r = self.bound();
+        let n = (r.x * r.y) as u32;
+        n - (self.elves.board.len() as u32)
+    }
+}
+
+pub struct Day023 {
+    map: Map,
+}
+
+impl Solution for Day023 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day023 {
+            map: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        let mut map = self.map.clone();
+        for _ in 0..10 {
+            map.tick();
+        }
+        map.empty_ground()
+    }
+
+    fn part2(&self) -> u32 {
+        let mut map = self.map.clone();
+        map.elves.until_fixpoint(|elves| elves.propose(spread)) as u32
+    }
+
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool {
+        let mut map = self.map.clone();
+        let frame = |map: &Map| format!("{map:?}round {}\n", map.elves.steps);
+        let mut watching = screen.show(&frame(&map));
+        while watching && (part != 1 || map.elves.steps < 10) && map.tick() {
+            watching = screen.show(&frame(&map));
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"..............
+..............
+.......#......
+.....###.#....
+...#...#.#....
+....#...##....
+...#.###......
+...##.#.##....
+....#..#......
+..............
+..............
+.............."#;
+    const SMALL_SAMPLE: &str = r#".....
+..##.
+..#..
+.....
+..##.
+....."#;
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(Map::from_str(SAMPLE).unwrap().elves.board.len(), 22);
+        let e = Map::from_str("..#\n.#x").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut map = Map::from_str(SMALL_SAMPLE).unwrap();
+        println!("{:?}", map);
+        assert_eq!(map.elves.steps, 0);
+        assert_eq!(map.bound(), Coord::new(2, 4));
+        map.tick();
+        println!("{:?}", map);
+        assert_eq!(map.elves.steps, 1);
+        assert_eq!(map.bound(), Coord::new(2, 5));
+    }
+
+    #[test]
+    fn test_big_tick() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        println!("{map:?}");
+        assert_eq!(map.elves.steps, 0);
+        assert_eq!(map.bound(), Coord::new(7, 7));
+        map.tick();
+        println!("{map:?}");
+        assert_eq!(map.elves.steps, 1);
+        assert_eq!(map.bound(), Coord::new(9, 9));
+    }
+
+    #[test]
+    fn test_empty_ground() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        for _ in 0..10 {
+            map.tick();
+        }
+        assert_eq!(map.elves.steps, 10);
+        println!("{map:?}");
+        assert_eq!(map.empty_ground(), 110);
+    }
+
+    #[test]
+    fn test_run_to_completion() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        for _ in 0..10 {
+            map.tick();
+        }
+        println!("{map:?}");
+        while map.tick() {}
+        println!("{map:?}");
+        assert_eq!(map.elves.steps, 20);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let day = Day023::parse(SAMPLE).unwrap();
+        let mut frames = Vec::new();
+        assert!(day.visualize(1, &mut frames));
+        assert_eq!(frames.len(), 11);
+        assert!(frames[10].ends_with("round 10\n"));
+        let mut frames = Vec::new();
+        assert!(day.visualize(2, &mut frames));
+        assert_eq!(frames.len(), 20);
+        assert!(frames[19].ends_with("round 19\n"));
+    }
+}
@@ -0,0 +1,353 @@
+use aoc::search::astar;
+use aoc::{Automaton, Board, Direction, Grid, Neighbourhood, ParseError, Point2, Point3, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+type Int = i32;
+type SCoord = Point2<Int>;
+type STCoord = Point3<Int>; // x, y and the time t as z
+
+use Direction::{East, North, South, West};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+struct Blizzard {
+    pos: SCoord,
+    dir: Direction,
+}
+
+/// Which ways the blizzards in a place are blowing, indexed by direction.
+type Winds = [bool; 4];
+
+/// The valley walls and all, with the winds wherever there are any.
+type State = Automaton<Grid<Option<Winds>>>;
+
+fn inside(p: SCoord, valley: SCoord) -> bool {
+    p.x >= 1 && p.x <= valley.x - 2 && p.y >= 1 && p.y <= valley.y - 2
+}
+
+fn wrap(mut p: SCoord, valley: SCoord) -> SCoord {
+    // #......# 1..valley-1
+    if p.x < 1 {
+        p.x = valley.x - 2;
+    }
+    if p.x >= valley.x - 1 {
+        p.x = 1;
+    }
+    if p.y < 1 {
+        p.y = valley.y - 2;
+    }
+    if p.y >= valley.y - 1 {
+        p.y = 1;
+    }
+    p
+}
+
+/// The winds that blow into a place: every blizzard one place upwind of it
+/// that is blowing this way.
+fn blow(valley: SCoord, place: &Neighbourhood<Grid<Option<Winds>>>) -> Option<Winds> {
+    if !inside(place.at, valley) {
+        return None;
+    }
+    let mut winds = [false; 4];
+    for d in Direction::ALL {
+        let upwind = wrap(place.at - d.offset(), valley);
+        winds[d as usize] = Board::get(place.board(), upwind).is_some_and(|w| w[d as usize]);
+    }
+    winds.contains(&true).then_some(winds)
+}
+
+#[derive(Clone, Debug)]
+struct Map {
+    #[allow(dead_code)] // only the tests look at the starting blizzards
+    blizzards: Vec<Blizzard>,
+    valley: SCoord,
+    start: SCoord,
+    end: SCoord,
+    states: HashMap<i32, State>,
+}
+impl Map {
+    fn at_time(&mut self, t: i32) -> &State {
+        if !self.states.contains_key(&t) {
+            let state = self.tick(
+                self.states
+                    .get(&(t - 1))
+                    .expect("search tried to step twice?"),
+            );
+            self.states.insert(t, state);
+        }
+        self.states.get(&t).unwrap()
+    }
+
+    fn tick(&self, t0: &State) -> State {
+        let mut next = t0.clone();
+        next.step(|place| blow(self.valley, place));
+        next
+    }
+
+    fn h(goal: SCoord, p: STCoord) -> usize {
+        goal.manhattan(&space(p)).try_into().unwrap()
+    }
+
+    fn can_step(&mut self, current: STCoord, offset: STCoord) -> Option<STCoord> {
+        let next = current + offset;
+        if self.end == space(next) || self.start == space(next) {
+            return Some(next);
+        }
+        if next.x < 1 || next.x > self.valley.x - 2 || next.y < 1 || next.y > self.valley.y - 2 {
+            return None;
+        }
+        let state = self.at_time(next.z);
+        if Board::get(&state.board, space(next)).is_some() {
+            return None;
+        }
+        Some(next)
+    }
+}
+
+/// Where a point in space and time is, whenever it is.
+fn space(p: STCoord) -> SCoord {
+    SCoord::new(p.x, p.y)
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut blizzards = Vec::new();
+        let mut states = HashMap::new();
+        let mut start = SCoord::new(0, 0);
+        let mut end = None;
+        let mut valley = SCoord::new(0, 1);
+        let mut lines = input.lines().enumerate();
+
+        // north wall
+        let (_, top) = lines
+            .next()
+            .ok_or_else(|| ParseError::line("there is no map", input))?;
+        valley.x = top.len() as i32;
+        start.x = top
+            .find('.')
+            .ok_or_else(|| ParseError::line("there is no door in the north wall", top))?
+            as i32;
+        let bottom = input.lines().count() - 1;
+        for (n, line) in lines {
+            let at = |e: ParseError| e.below(n);
+            let j = valley.y;
+            if line.len() != top.len() {
+                let reason = format!("row is {} wide, not {}", line.len(), top.len());
+                return Err(at(ParseError::line(reason, line)));
+            }
+            if n == bottom {
+                // south wall
+                let door = line.find('.').ok_or_else(|| {
+                    at(ParseError::line("there is no door in the south wall", line))
+                })?;
+                end = Some(SCoord::new(door as i32, valley.y));
+            } else {
+                for (i, c) in line.char_indices().take(top.len() - 1).skip(1) {
+                    let dir = match c {
+                        '.' => continue,
+                        '^' => North,
+                        '>' => East,
+                        'v' => South,
+                        '<' => West,
+                        _ => {
+                            let reason = match c {
+                                '#' => "found a wall inside the valley",
+                                _ => "illegal map character",
+                            };
+                            let bad = &line[i..i + c.len_utf8()];
+                            return Err(at(ParseError::new(reason, line, bad)));
+                        }
+                    };
+                    blizzards.push(Blizzard {
+                        pos: SCoord::new(i as i32, j),
+                        dir,
+                    });
+                }
+            }
+            valley.y += 1;
+        }
+        let end = end.ok_or_else(|| ParseError::line("valley ends without a south wall", top))?;
+        let mut winds = Grid::new(valley.x as usize, valley.y as usize, None);
+        for b in blizzards.iter() {
+            let place: &mut Option<Winds> = &mut winds[(b.pos.x as usize, b.pos.y as usize)];
+            place.get_or_insert([false; 4])[b.dir as usize] = true;
+        }
+        states.insert(0, State::new(winds));
+        Ok(Map {
+            blizzards,
+            valley,
+            start,
+            end,
+            states,
+        })
+    }
+}
+
+/// Walks to each of `goals` in turn, or just to the end when there are none,
+/// and returns how many minutes the whole trip took.
+fn shortest_path_through_spacetime(map: &mut Map, goals: &[SCoord]) -> usize {
+    let legs = if goals.is_empty() {
+        vec![map.end]
+    } else {
+        goals.to_vec()
+    };
+    let mut here = STCoord::new(map.start.x, map.start.y, 0);
+    // a minute passes whether we step to a neighbour or wait where we are
+    let offsets: Vec<STCoord> = SCoord::default()
+        .neighbours4()
+        .chain([SCoord::default()])
+        .map(|d| STCoord::new(d.x, d.y, 1))
+        .collect();
+    for goal in legs {
+        let steps = |p: &STCoord| {
+            offsets
+                .iter()
+                .filter_map(|offset| map.can_step(*p, *offset))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let arrived = |p: &STCoord| space(*p) == goal;
+        let path = astar(here, steps, arrived, |p| Map::h(goal, *p))
+            .expect("there is no way through the valley");
+        here = *path.nodes.last().expect("a path ends somewhere");
+    }
+    here.z as usize
+}
+
+pub struct Day024 {
+    map: Map,
+}
+
+impl Solution for Day024 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day024 {
+            map: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        shortest_path_through_spacetime(&mut self.map.clone(), &[])
+    }
+
+    fn part2(&self) -> usize {
+        let mut map = self.map.clone();
+        let goals = [map.end, map.start, map.end];
+        shortest_path_through_spacetime(&mut map, &goals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#"#;
+
+    #[test]
+    fn test_parse_input() {
+        let e = Map::from_str(&SAMPLE.replacen("#.<..<<#", "#.<.#<<#", 1))
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.reason.as_str()),
+            (3, 5, "found a wall inside the valley")
+        );
+        let e = Map::from_str(&SAMPLE.replacen("#>v.><>#", "#>v.?<>#", 1))
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 5, "?"));
+        let e = Map::from_str(&SAMPLE.replacen("#<^v^^>#", "#<^v^^>", 1))
+            .err()
+            .unwrap();
+        assert_eq!(e.line, 5);
+        let e = Map::from_str(&SAMPLE[..SAMPLE.rfind('\n').unwrap()])
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (5, "there is no door in the south wall")
+        );
+        let e = Map::from_str("#.###").err().unwrap();
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (1, "valley ends without a south wall")
+        );
+        let map = Map::from_str(SAMPLE).unwrap();
+        assert_eq!(map.start, SCoord::new(1, 0), "wrong start");
+        assert_eq!(map.end, SCoord::new(6, 5), "wrong end");
+        assert_eq!(map.valley, SCoord::new(8, 6), "wrong valley size");
+        assert_eq!(map.blizzards.len(), 19);
+        assert_eq!(map.blizzards[0].pos, SCoord::new(1, 1));
+        assert_eq!(map.blizzards[0].dir, East);
+        assert_eq!(map.blizzards[9].pos, SCoord::new(2, 3));
+        assert_eq!(map.blizzards[9].dir, South);
+        assert_eq!(map.blizzards[2].pos, SCoord::new(4, 1));
+        assert_eq!(map.blizzards[2].dir, West);
+        assert_eq!(map.blizzards[3].pos, SCoord::new(5, 1));
+        assert_eq!(map.blizzards[3].dir, North);
+        assert_eq!(map.blizzards[18].pos, SCoord::new(6, 4));
+        assert_eq!(map.blizzards[18].dir, East);
+    }
+    fn blowing(state: &State, x: Int, y: Int) -> Vec<Direction> {
+        let winds = Board::get(&state.board, SCoord::new(x, y)).copied().unwrap_or_default();
+        Direction::ALL.into_iter().filter(|d| winds[*d as usize]).collect()
+    }
+
+    #[test]
+    fn test_at_t_0() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        let state = map.at_time(0);
+        assert_eq!(state.board.iter().flatten().flatten().filter(|w| **w).count(), 19);
+        assert_eq!(blowing(state, 1, 1), [East]);
+        assert_eq!(blowing(state, 2, 3), [South]);
+        assert_eq!(blowing(state, 4, 1), [West]);
+        assert_eq!(blowing(state, 5, 1), [North]);
+        assert_eq!(blowing(state, 6, 4), [East]);
+        assert_eq!(blowing(state, 3, 1), []);
+    }
+    #[test]
+    fn test_at_t_1() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        let state = map.at_time(1);
+        assert_eq!(state.steps, 1);
+        assert_eq!(state.board.iter().flatten().flatten().filter(|w| **w).count(), 19);
+        // straight
+        assert!(blowing(state, 2, 1).contains(&East));
+        assert!(blowing(state, 2, 4).contains(&South));
+        assert!(blowing(state, 3, 1).contains(&West));
+        assert!(blowing(state, 2, 3).contains(&North));
+        // wrapped
+        assert!(blowing(state, 1, 3).contains(&East));
+        assert!(blowing(state, 3, 1).contains(&South));
+        assert!(blowing(state, 6, 4).contains(&West));
+        assert!(blowing(state, 5, 4).contains(&North));
+    }
+    #[test]
+    fn test_at_t_5() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        for t in 0..5 {
+            map.at_time(t);
+        }
+        let state = map.at_time(5);
+        assert!(Board::get(&state.board, SCoord::new(1, 1)).is_some());
+    }
+    #[test]
+    fn test_path_finder() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        assert_eq!(shortest_path_through_spacetime(&mut map, &[]), 18);
+    }
+    #[test]
+    fn test_multipath() {
+        let mut map = Map::from_str(SAMPLE).unwrap();
+        let goals = [map.end, map.start, map.end];
+        assert_eq!(shortest_path_through_spacetime(&mut map, &goals), 54);
+    }
+}
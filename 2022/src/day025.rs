@@ -1,4 +1,4 @@
-use std::fs;
+use aoc::{ParseError, Solution, Unsolved};
 
 fn encode(n: isize) -> String {
     // 5, 25, 125, 625, 3_125, 15_625
@@ -8,8 +8,8 @@ fn encode(n: isize) -> String {
         exp += 1;
     }
     exp += 1;
-    let mut values = std::iter::repeat(0).take(exp).collect::<Vec<isize>>();
-    let mut carry = std::iter::repeat(false).take(exp).collect::<Vec<bool>>();
+    let mut values = vec![0_isize; exp];
+    let mut carry = vec![false; exp];
 
     for i in (0..exp).rev() {
         let base = 5_isize.pow(i as u32);
@@ -74,14 +74,32 @@ fn decode(s: &str) -> isize {
     result
 }
 
-fn main() {
-    let input: &str = &fs::read_to_string("input/025.txt").expect("file read error");
-    println!("please enter: {}", encode(input.lines().map(decode).sum()));
+pub struct Day025 {
+    fuel: Vec<isize>,
+}
+
+impl Solution for Day025 {
+    type Part1 = String;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day025 {
+            fuel: input.lines().map(decode).collect(),
+        })
+    }
+
+    fn part1(&self) -> String {
+        encode(self.fuel.iter().sum())
+    }
+
+    fn part2(&self) -> Unsolved {
+        Unsolved
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"1=-0-2
 12111
 2=0=
@@ -0,0 +1,74 @@
+//! Advent of Code 2022, one module per day, all registered in `calendar()`.
+
+use aoc::{Calendar, Day};
+
+mod day001;
+mod day002;
+mod day003;
+mod day004;
+mod day005;
+mod day006;
+mod day007;
+mod day008;
+mod day009;
+mod day010;
+mod day011;
+mod day012;
+mod day013;
+mod day014;
+mod day015;
+mod day016;
+mod day017;
+mod day018;
+mod day019;
+mod day020;
+mod day021;
+mod day022;
+mod day023;
+mod day024;
+mod day025;
+
+/// The day 7 filesystem, to explore with `aoc shell`.
+pub use day007::Shell;
+/// The day 10 program in a debugger, for `aoc debug`.
+pub use day010::debugger;
+
+pub fn calendar() -> Calendar {
+    Calendar::new(
+        2022,
+        env!("CARGO_MANIFEST_DIR"),
+        vec![
+            Day::new::<day001::Day001>(1),
+            Day::new::<day002::Day002>(2),
+            Day::new::<day003::Day003>(3),
+            Day::new::<day004::Day004>(4),
+            Day::new::<day005::Day005>(5),
+            Day::new::<day006::Day006>(6),
+            Day::new::<day007::Day007>(7),
+            Day::new::<day008::Day008>(8),
+            Day::new::<day009::Day009>(9),
+            Day::new::<day010::Day010>(10),
+            Day::new::<day011::Day011>(11),
+            Day::new::<day012::Day012>(12),
+            Day::new::<day013::Day013>(13),
+            Day::new::<day014::Day014>(14),
+            Day::new::<day015::Day015>(15),
+            Day::new::<day016::Day016>(16),
+            Day::new::<day017::Day017>(17),
+            Day::new::<day018::Day018>(18),
+            Day::new::<day019::Day019>(19),
+            Day::new::<day020::Day020>(20),
+            Day::new::<day021::Day021>(21),
+            Day::new::<day022::Day022>(22),
+            Day::new::<day023::Day023>(23),
+            Day::new::<day024::Day024>(24),
+            Day::new::<day025::Day025>(25),
+        ],
+    )
+}
+
+/// One test per part of every `input/NNN-sample-K.txt`, see `aoc::sample`.
+#[cfg(test)]
+mod samples {
+    include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+}
@@ -1,20 +1,28 @@
+use aoc::{ParseError, Solution};
 use lazy_static::lazy_static; // 1.3.0
 use regex::Regex;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-fn main() {
-    let f = File::open("input/001.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let numbers = parse_numbers(lines.clone());
-    println!("numerical sum is {:?}", numbers.iter().sum::<u32>());
-    let words = parse_words(lines);
-    println!("word sum is {:?}", words.iter().sum::<u32>());
+pub struct Day001 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day001 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day001 {
+            lines: aoc::lines(input),
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        parse_numbers(self.lines.clone()).iter().sum()
+    }
+
+    fn part2(&self) -> u32 {
+        parse_words(self.lines.clone()).iter().sum()
+    }
 }
 
 const CALIB_2_RE: &str = r"^[^\d]*(\d).*(\d)[^\d]*$";
@@ -83,8 +91,8 @@ fn parse_words(lines: Vec<String>) -> Vec<u32> {
 
 #[cfg(test)]
 mod tests {
-    use crate::parse_number;
-    use crate::parse_word;
+    use super::parse_number;
+    use super::parse_word;
     #[test]
     fn test_numbers() {
         assert_eq!(parse_number("1abc2"), 12);
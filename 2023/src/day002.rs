@@ -1,7 +1,5 @@
+use aoc::{ParseError, Solution};
 use std::cmp::max;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 #[derive(Debug)]
 struct Game {
@@ -52,7 +50,7 @@ impl Sample {
     }
 }
 
-fn score(games: &Vec<Game>, probe: &Sample) -> u32 {
+fn score(games: &[Game], probe: &Sample) -> u32 {
     games
         .iter()
         .filter(|g| g.possible(probe))
@@ -60,28 +58,39 @@ fn score(games: &Vec<Game>, probe: &Sample) -> u32 {
         .sum::<u32>()
 }
 
-fn power_score(games: &Vec<Game>) -> u32 {
+fn power_score(games: &[Game]) -> u32 {
     games
         .iter()
         .map(|g| g.minimum())
         .map(|s| s.power())
         .sum::<u32>()
 }
-fn main() {
-    let f = File::open("input/002.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let games = parse_games(lines);
-    let probe = Sample {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-    println!("12/13/14 total is {:?}", score(&games, &probe));
-    println!("12/13/14 total is {:?}", power_score(&games));
+pub struct Day002 {
+    games: Vec<Game>,
+}
+
+impl Solution for Day002 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day002 {
+            games: parse_games(aoc::lines(input)),
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        let probe = Sample {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        score(&self.games, &probe)
+    }
+
+    fn part2(&self) -> u32 {
+        power_score(&self.games)
+    }
 }
 
 fn parse_game(input: &str) -> Game {
@@ -131,7 +140,7 @@ fn parse_games(lines: Vec<String>) -> Vec<Game> {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
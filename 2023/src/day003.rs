@@ -0,0 +1,283 @@
+use aoc::{Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Number {
+    v: u32,
+    p: (usize, usize),
+    n: usize,
+}
+trait Cog {
+    fn is_gear(&self, numbers: &[Number]) -> Option<Gear>;
+}
+
+#[derive(Debug)]
+struct Gear {
+    pieces: HashSet<Number>,
+}
+
+impl Number {
+    fn adjacent(&self, schematic: &Grid<char>) -> bool {
+        let i = self.p.0;
+        (self.p.1..(self.p.1 + self.n)).any(|j| {
+            schematic.neighbours8((j, i)).any(|n| {
+                let c = schematic[n];
+                c != '.' && !c.is_numeric()
+            })
+        })
+    }
+
+    fn covers_any(&self, joins: &[(usize, usize)]) -> bool {
+        joins
+            .iter()
+            .any(|j| self.p.0 == j.0 && (j.1 >= self.p.1 && j.1 < (self.p.1 + self.n)))
+    }
+}
+
+impl Cog for (usize, usize) {
+    fn is_gear(&self, numbers: &[Number]) -> Option<Gear> {
+        let i = self.0 as i32;
+        let j = self.1 as i32;
+        let mut joins = Vec::new();
+        for di in -1..2 {
+            for dj in -1..2 {
+                joins.push(((i + di) as usize, (j + dj) as usize));
+            }
+        }
+        let mut pieces = HashSet::new();
+        for piece in numbers {
+            if piece.covers_any(&joins) {
+                pieces.insert(piece.clone());
+            }
+        }
+        if pieces.len() >= 2 {
+            Some(Gear { pieces })
+        } else {
+            None
+        }
+    }
+}
+
+fn load_schematic(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input, Some)
+}
+
+fn find_numbers(schematic: &Grid<char>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    for (i, row) in schematic.rows().enumerate() {
+        let mut inhand = None;
+        let len = row.len();
+        for j in 0..(len + 1) {
+            if j < len && row[j].is_ascii_digit() {
+                if inhand.is_none() {
+                    inhand = Some((i, j));
+                }
+            } else if let Some(p) = inhand {
+                let s: String = row[p.1..j].iter().collect();
+                let v = s.parse::<u32>().expect("found a non-integer");
+                let n = j - p.1;
+                numbers.push(Number { v, p, n });
+                inhand = None;
+            }
+        }
+    }
+    numbers
+}
+
+fn find_cogs(schematic: &Grid<char>) -> Vec<(usize, usize)> {
+    schematic
+        .indexed()
+        .filter(|(_, c)| **c == '*')
+        .map(|((j, i), _)| (i, j))
+        .collect()
+}
+
+pub struct Day003 {
+    schematic: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+impl Solution for Day003 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let schematic = load_schematic(input)?;
+        let numbers = find_numbers(&schematic);
+        Ok(Day003 { schematic, numbers })
+    }
+
+    fn part1(&self) -> u32 {
+        // 528231 too low
+        score_adjacent(&self.numbers, &self.schematic)
+    }
+
+    fn part2(&self) -> u32 {
+        let cogs = find_cogs(&self.schematic);
+        let gears: Vec<_> = cogs
+            .iter()
+            .filter_map(|c| c.is_gear(&self.numbers))
+            .collect();
+        score_gears(&gears)
+    }
+}
+
+fn score_adjacent(numbers: &[Number], schematic: &Grid<char>) -> u32 {
+    numbers
+        .iter()
+        .filter(|n| n.adjacent(schematic))
+        .map(|n| n.v)
+        .sum::<u32>()
+}
+
+fn score_gears(gears: &[Gear]) -> u32 {
+    gears
+        .iter()
+        .map(|g| g.pieces.iter().map(|p| p.v).product::<u32>())
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+    const SAMPLE2: &str = r#"467..114"#;
+
+    #[test]
+    fn test_load() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        assert_eq!(schematic.height(), 10);
+        assert_eq!(schematic.width(), 10);
+        assert_eq!(schematic[(3, 1)], '*');
+        assert_eq!(schematic[(2, 4)], '7');
+    }
+
+    #[test]
+    fn test_find() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        let numbers = find_numbers(&schematic);
+        assert_eq!(numbers.len(), 10);
+        assert_eq!(
+            numbers[0],
+            Number {
+                v: 467,
+                p: (0, 0),
+                n: 3
+            }
+        );
+        assert_eq!(
+            numbers[2],
+            Number {
+                v: 35,
+                p: (2, 2),
+                n: 2
+            }
+        );
+        assert_eq!(
+            numbers[6],
+            Number {
+                v: 592,
+                p: (6, 2),
+                n: 3
+            }
+        );
+        assert_eq!(
+            numbers[9],
+            Number {
+                v: 598,
+                p: (9, 5),
+                n: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_eol() {
+        let schematic = load_schematic(SAMPLE2).unwrap();
+        let numbers = find_numbers(&schematic);
+        assert_eq!(numbers.len(), 2);
+        assert_eq!(
+            numbers[0],
+            Number {
+                v: 467,
+                p: (0, 0),
+                n: 3
+            }
+        );
+        assert_eq!(
+            numbers[1],
+            Number {
+                v: 114,
+                p: (0, 5),
+                n: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_adjacent() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        assert!(Number {
+            v: 467,
+            p: (0, 0),
+            n: 3
+        }
+        .adjacent(&schematic));
+        assert!(Number {
+            v: 35,
+            p: (2, 2),
+            n: 2
+        }
+        .adjacent(&schematic));
+        assert!(!Number {
+            v: 114,
+            p: (0, 6),
+            n: 3
+        }
+        .adjacent(&schematic));
+        assert!(!Number {
+            v: 58,
+            p: (5, 7),
+            n: 2
+        }
+        .adjacent(&schematic));
+    }
+
+    #[test]
+    fn test_score_adjacent() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        let numbers = find_numbers(&schematic);
+        let sum = score_adjacent(&numbers, &schematic);
+        assert_eq!(sum, 4361);
+    }
+
+    #[test]
+    fn test_find_cogs() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        let cogs = find_cogs(&schematic);
+        assert_eq!(cogs.len(), 3);
+        assert_eq!(cogs[0], (1, 3));
+        assert_eq!(cogs[1], (4, 3));
+        assert_eq!(cogs[2], (8, 5));
+    }
+
+    #[test]
+    fn test_score_gears() {
+        let schematic = load_schematic(SAMPLE).unwrap();
+        let numbers = find_numbers(&schematic);
+        let cogs = find_cogs(&schematic);
+        let gears: Vec<_> = cogs.iter().filter_map(|c| c.is_gear(&numbers)).collect();
+        assert_eq!(gears.len(), 2);
+        let _sum = score_gears(&gears);
+        assert_eq!(gears.len(), 2);
+    }
+}
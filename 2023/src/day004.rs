@@ -1,7 +1,5 @@
+use aoc::{ParseError, Solution};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::str::FromStr;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -81,24 +79,32 @@ fn score_deck(cards: &Vec<Card>) -> u32 {
     inventory.iter().sum()
 }
 
-fn main() {
-    let f = File::open("input/004.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let cards = load_cards(lines);
-    let total: u32 = cards.iter().map(score_card).sum();
-    println!("total score is {total}");
-
-    let card_count = score_deck(&cards);
-    println!("total card count is {card_count}");
+pub struct Day004 {
+    cards: Vec<Card>,
+}
+
+impl Solution for Day004 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day004 {
+            cards: load_cards(aoc::lines(input)),
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        self.cards.iter().map(score_card).sum()
+    }
+
+    fn part2(&self) -> u32 {
+        score_deck(&self.cards)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
@@ -0,0 +1,399 @@
+use aoc::{Interval, IntervalSet, ParseError, RangeMap, Solution};
+
+type Lot = IntervalSet<u64>;
+
+const STAGES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<RangeMap<u64>>, // one per STAGES
+}
+
+impl Almanac {
+    fn load_map(j: usize, lines: &[String]) -> Result<(usize, RangeMap<u64>), ParseError> {
+        let mut map = Vec::new();
+        let mut i = j;
+        // destination start, source start, range length
+        while i < lines.len() && !lines[i].is_empty() {
+            let line = &lines[i];
+            let at = |e: ParseError| e.below(i);
+            let args: Vec<_> = line.split(' ').collect();
+            let [d, s, l] = args[..] else {
+                let reason = "expected destination, source and length";
+                return Err(at(ParseError::line(reason, line)));
+            };
+            let number = |n: &str| {
+                n.parse::<u64>()
+                    .map_err(|_| at(ParseError::new("map value is not a number", line, n)))
+            };
+            let (d, s, l) = (number(d)?, number(s)?, number(l)?);
+            // an empty range maps nothing
+            if l > 0 {
+                let hi = s.checked_add(l - 1).ok_or_else(|| {
+                    at(ParseError::line("range runs past the largest number", line))
+                })?;
+                map.push((Interval { lo: s, hi }, d));
+            }
+            i += 1;
+        }
+        Ok((i + 1, map.into_iter().collect()))
+    }
+
+    /// Every seed range the first line really lists, for part 2. Parsing
+    /// made sure the seeds pair up and that no range runs off the end.
+    fn lot(&self) -> Lot {
+        self.seeds
+            .chunks_exact(2)
+            .filter(|c| c[1] > 0)
+            .map(|c| Interval {
+                lo: c[0],
+                hi: c[0] + (c[1] - 1),
+            })
+            .collect()
+    }
+
+    /// All seven maps one after another, as one map.
+    fn seed_to_location(&self) -> RangeMap<u64> {
+        self.maps
+            .iter()
+            .fold(RangeMap::identity(), |chain, map| chain.then(map))
+    }
+}
+
+impl TryFrom<&Vec<String>> for Almanac {
+    type Error = ParseError;
+
+    fn try_from(lines: &Vec<String>) -> Result<Self, ParseError> {
+        let first = lines.first().map_or("", String::as_str);
+        let numbers = first
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::line("expected `seeds: `", first))?;
+        let seeds = numbers
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|n| {
+                n.parse::<u64>()
+                    .map_err(|_| ParseError::new("seed is not a number", first, n))
+            })
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        if seeds.is_empty() {
+            return Err(ParseError::line("there are no seeds", first));
+        }
+        if seeds.len() % 2 == 1 {
+            return Err(ParseError::line("seeds don't pair up into ranges", first));
+        }
+        if let Some(c) = seeds
+            .chunks(2)
+            .find(|c| c[1] > 0 && c[0].checked_add(c[1] - 1).is_none())
+        {
+            let reason = format!("seed range {} {} runs past the largest number", c[0], c[1]);
+            return Err(ParseError::line(reason, first));
+        }
+
+        let mut maps = Vec::new();
+        let mut i = 2;
+        for stage in STAGES {
+            let header = format!("{stage} map:");
+            match lines.get(i) {
+                Some(line) if *line == header => {}
+                Some(line) => {
+                    let reason = format!("expected `{header}`");
+                    return Err(ParseError::line(reason, line).below(i));
+                }
+                None => {
+                    let last = lines.len() - 1;
+                    let reason = format!("almanac ends before the {stage} map");
+                    return Err(ParseError::line(reason, &lines[last]).below(last));
+                }
+            }
+            let (next, map) = Almanac::load_map(i + 1, lines)?;
+            maps.push(map);
+            i = next;
+        }
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+pub struct Day005 {
+    almanac: Almanac,
+    seed_to_location: RangeMap<u64>,
+}
+
+impl Solution for Day005 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let almanac = Almanac::try_from(&aoc::lines(input))?;
+        Ok(Day005 {
+            seed_to_location: almanac.seed_to_location(),
+            almanac,
+        })
+    }
+
+    fn part1(&self) -> u64 {
+        self.almanac
+            .seeds
+            .iter()
+            .map(|&seed| self.seed_to_location.get(seed))
+            .min()
+            .expect("there's no minimum?")
+    }
+
+    fn part2(&self) -> u64 {
+        let locations = self.seed_to_location.push(&self.almanac.lot());
+        locations.first().expect("no seeds to plant")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lot(pairs: &[(u64, u64)]) -> Lot {
+        pairs.iter().map(|&(lo, hi)| Interval { lo, hi }).collect()
+    }
+
+    const SAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+    #[test]
+    fn test_lambdas() {
+        let offsets = [4, 2, 9, 1];
+        let mut operators = Vec::new();
+        for offset in offsets {
+            operators.push(Box::new(move |x| x + offset));
+        }
+        assert_eq!((*operators[0])(1), 5);
+        assert_eq!((*operators[1])(1), 3);
+        assert_eq!((*operators[2])(1), 10);
+        assert_eq!((*operators[3])(1), 2);
+    }
+
+    fn almanac() -> Almanac {
+        let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        Almanac::try_from(&lines).unwrap()
+    }
+
+    fn map(pieces: &[(u64, u64, u64)]) -> RangeMap<u64> {
+        pieces
+            .iter()
+            .map(|&(lo, hi, destination)| (Interval { lo, hi }, destination))
+            .collect()
+    }
+
+    #[test]
+    fn test_load() {
+        let almanac = almanac();
+        assert_eq!(almanac.seeds.len(), 4);
+        assert_eq!(almanac.maps.len(), 7);
+        // pieces that carry straight on from one another are joined up
+        let pieces = almanac
+            .maps
+            .iter()
+            .map(|m| m.pieces().len())
+            .collect::<Vec<_>>();
+        assert_eq!(pieces, [2, 2, 4, 2, 3, 2, 2]);
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let load = |text: &str| {
+            let lines = text.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+            Almanac::try_from(&lines).err().unwrap()
+        };
+        let e = load(&SAMPLE.replace("seeds: ", "seed: "));
+        assert_eq!((e.line, e.reason.as_str()), (1, "expected `seeds: `"));
+        let e = load(&SAMPLE.replace("55 13", "55 1e"));
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 17, "1e"));
+        let e = load(&SAMPLE.replace("water-to-light", "water-to-lite"));
+        assert_eq!(e.line, 18);
+        assert_eq!(e.reason, "expected `water-to-light map:`");
+        let e = load(&SAMPLE[..SAMPLE.find("humidity-to-location").unwrap()]);
+        assert_eq!(e.line, 30);
+        assert_eq!(e.reason, "almanac ends before the humidity-to-location map");
+        let e = load(&SAMPLE.replace("52 50 48", "52 50"));
+        assert_eq!((e.line, e.text.as_str()), (5, "52 50"));
+        let e = load(&SAMPLE.replace("52 50 48", "52 fifty 48"));
+        assert_eq!((e.line, e.column, e.text.as_str()), (5, 4, "fifty"));
+        let e = load(&SAMPLE.replace("52 50 48", "52 18446744073709551615 2"));
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (5, "range runs past the largest number")
+        );
+        let e = load(&SAMPLE.replace("55 13", "55"));
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (1, "seeds don't pair up into ranges")
+        );
+        let e = load(&SAMPLE.replace("79 14 55 13", ""));
+        assert_eq!((e.line, e.reason.as_str()), (1, "there are no seeds"));
+        let e = load(&SAMPLE.replace("55 13", "18446744073709551615 2"));
+        assert_eq!(e.line, 1);
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        let lines = SAMPLE
+            .replace("79 14", "79 0")
+            .replace("52 50 48", "52 50 0")
+            .lines()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let almanac = Almanac::try_from(&lines).unwrap();
+        assert_eq!(almanac.lot(), lot(&[(55, 67)]));
+        assert_eq!(almanac.maps[0].get(79), 79);
+        assert_eq!(almanac.maps[0].get(98), 50);
+    }
+
+    #[test]
+    fn test_mapper() {
+        let almanac = almanac();
+        assert_eq!(almanac.maps[0].get(79), 81);
+        assert_eq!(almanac.maps[0].get(14), 14);
+        assert_eq!(almanac.maps[0].get(55), 57);
+        assert_eq!(almanac.maps[0].get(13), 13);
+    }
+
+    #[test]
+    fn test_seed_to_location() {
+        let processor = almanac().seed_to_location();
+        assert_eq!(processor.get(79), 82);
+        assert_eq!(processor.get(14), 43);
+        assert_eq!(processor.get(55), 86);
+        assert_eq!(processor.get(13), 35);
+    }
+
+    #[test]
+    fn test_invert() {
+        let processor = almanac().seed_to_location();
+        let back = processor.invert().expect("every map shuffles its numbers");
+        assert_eq!(back.get(46), 82);
+        assert_eq!(back.then(&processor), RangeMap::identity());
+    }
+
+    #[test]
+    fn test_closest() {
+        let day = Day005::parse(SAMPLE).unwrap();
+        assert_eq!(day.part1(), 35);
+    }
+
+    #[test]
+    fn test_lots() {
+        //  79 14 55 13
+        assert_eq!(almanac().lot(), lot(&[(55, 67), (79, 92)]));
+    }
+
+    #[test]
+    fn test_grind_one_seed() {
+        let stones = map(&[(98, 100, 50), (50, 98, 52)]);
+        assert_eq!(stones.push(&lot(&[(82, 82)])), lot(&[(84, 84)]));
+    }
+
+    #[test]
+    fn test_grind_disjoint_before() {
+        let stones = map(&[(98, 100, 50), (50, 98, 52)]);
+        assert_eq!(stones.push(&lot(&[(20, 40)])), lot(&[(20, 40)]));
+    }
+
+    #[test]
+    fn test_grind_disjoint_after() {
+        let stones = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(stones.push(&lot(&[(100, 110)])), lot(&[(100, 110)]));
+    }
+
+    #[test]
+    fn test_grind_overlap_before() {
+        let stones = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(stones.push(&lot(&[(40, 59)])), lot(&[(40, 49), (52, 61)]));
+    }
+
+    #[test]
+    fn test_grind_overlap_after_first() {
+        let stones = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(
+            stones.push(&lot(&[(99, 109)])),
+            lot(&[(51, 51), (100, 109)])
+        );
+    }
+
+    #[test]
+    fn test_grind_overlap_after_second() {
+        let stones = map(&[(50, 97, 52), (98, 99, 50)]);
+        assert_eq!(
+            stones.push(&lot(&[(99, 109)])),
+            lot(&[(51, 51), (100, 109)])
+        );
+    }
+
+    #[test]
+    fn test_grind_overlap_two_stones() {
+        let stones = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(stones.push(&lot(&[(90, 100)])), lot(&[(50, 51), (92, 100)]));
+    }
+
+    #[test]
+    fn test_grind_complete_overlap() {
+        let stones = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(
+            stones.push(&lot(&[(40, 130)])),
+            lot(&[(40, 49), (50, 51), (52, 99), (100, 130)])
+        );
+    }
+
+    #[test]
+    fn test_winning_path() {
+        let almanac = almanac();
+        let path = [82, 84, 84, 84, 77, 45, 46, 46];
+        for (map, step) in almanac.maps.iter().zip(path.windows(2)) {
+            let pushed = map.push(&lot(&[(step[0], step[0])]));
+            assert_eq!(pushed.first(), Some(step[1]));
+        }
+    }
+
+    #[test]
+    fn test_first_harvest() {
+        let day = Day005::parse(SAMPLE).unwrap();
+        assert_eq!(day.part2(), 46);
+    }
+}
@@ -1,3 +1,5 @@
+use aoc::{ParseError, Solution};
+
 struct Race {
     t: u64,
     d: u64,
@@ -27,29 +29,58 @@ fn compete(events: &Vec<Race>) -> u64 {
     power
 }
 
-fn main() {
-    // Time:        61     70     90     66
-    // Distance:   643   1184   1362   1041
-    let shorts = vec![
-        Race { t: 61, d: 643 },
-        Race { t: 70, d: 1184 },
-        Race { t: 90, d: 1362 },
-        Race { t: 66, d: 1041 },
-    ];
-    let power = compete(&shorts);
-    println!("product of winning combos is {power}");
-
+fn parse_races(input: &str) -> (Vec<Race>, Race) {
+    let mut rows = input.lines().map(|l| {
+        l.split_whitespace()
+            .skip(1)
+            .map(String::from)
+            .collect::<Vec<String>>()
+    });
+    let times = rows.next().expect("no times");
+    let distances = rows.next().expect("no distances");
+    let number = |s: &str| s.parse::<u64>().expect("not a number");
+    let shorts = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| Race {
+            t: number(t),
+            d: number(d),
+        })
+        .collect();
+    // the kerning was bad, the spaces were never there
     let long = Race {
-        t: 61709066,
-        d: 643118413621041u64,
+        t: number(&times.concat()),
+        d: number(&distances.concat()),
     };
-    let ways_to_win = win_count(&long);
-    println!("number of winning strategies is {ways_to_win}");
+    (shorts, long)
+}
+
+pub struct Day006 {
+    shorts: Vec<Race>,
+    long: Race,
+}
+
+impl Solution for Day006 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (shorts, long) = parse_races(input);
+        Ok(Day006 { shorts, long })
+    }
+
+    fn part1(&self) -> u64 {
+        compete(&self.shorts)
+    }
+
+    fn part2(&self) -> u64 {
+        win_count(&self.long)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     use lazy_static::lazy_static; // 1.3.0
 
     lazy_static! {
@@ -89,4 +120,12 @@ mod tests {
         let power = win_count(&LONG);
         assert_eq!(power, 71503);
     }
+
+    #[test]
+    fn test_parse_races() {
+        let (shorts, long) = parse_races(include_str!("../input/006-sample-1.txt"));
+        assert_eq!(shorts.len(), 3);
+        assert_eq!((shorts[2].t, shorts[2].d), (30, 200));
+        assert_eq!((long.t, long.d), (LONG.t, LONG.d));
+    }
 }
@@ -1,10 +1,8 @@
+use aoc::{ParseError, Solution};
 use lazy_static::lazy_static;
 use std::cmp::min;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 #[derive(Clone)]
 struct Deck {
@@ -20,50 +18,62 @@ struct Hand {
     wild: Option<char>,
 }
 
-impl From<&String> for Hand {
-    fn from(line: &String) -> Self {
-        if let Some((c, b)) = line.split_once(' ') {
-            let bid = b.parse::<u32>().unwrap();
-            let cards = c.chars().collect::<Vec<char>>();
-            let class = Hand::classify(&cards);
-            Hand {
-                cards,
-                bid,
-                class,
-                wild: None,
-            }
-        } else {
-            panic!("bad hand {line}");
+impl TryFrom<&String> for Hand {
+    type Error = ParseError;
+
+    fn try_from(line: &String) -> Result<Self, ParseError> {
+        let (c, b) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::line("hand has no bid", line))?;
+        if let Some((i, card)) = c.char_indices().find(|(_, card)| !FACE_VALUE.contains(card)) {
+            let card = &c[i..i + card.len_utf8()];
+            return Err(ParseError::new("unknown card", line, card));
         }
-    }
-}
-impl From<&Vec<String>> for Deck {
-    fn from(lines: &Vec<String>) -> Self {
-        Deck {
-            hands: lines.iter().map(Hand::from).collect(),
+        let bid = b
+            .parse::<u32>()
+            .map_err(|_| ParseError::new("bid is not a number", line, b))?;
+        let cards = c.chars().collect::<Vec<char>>();
+        let class = Hand::classify(&cards);
+        Ok(Hand {
+            cards,
+            bid,
+            class,
             wild: None,
-        }
+        })
     }
 }
+impl TryFrom<&Vec<String>> for Deck {
+    type Error = ParseError;
 
-impl Ord for Hand {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+    fn try_from(lines: &Vec<String>) -> Result<Self, ParseError> {
+        let hands = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Hand::try_from(line).map_err(|e| e.below(i)))
+            .collect::<Result<_, _>>()?;
+        Ok(Deck { hands, wild: None })
     }
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self.class == other.class {
             let n = min(self.cards.len(), other.cards.len());
             for i in 0..n {
                 if self.cards[i] != other.cards[i] {
-                    return Hand::compare_cards(&self.cards[i], &other.cards[i], self.wild);
+                    return Hand::compare_cards(&self.cards[i], &other.cards[i], self.wild)
+                        .expect("unknown card");
                 }
             }
-            return Some(Ordering::Equal);
+            return Ordering::Equal;
         }
-        Some(self.class.cmp(&other.class))
+        self.class.cmp(&other.class)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -92,20 +102,17 @@ impl Hand {
         }
         let mut num_wild = 0;
         if let Some(w) = wild {
-            match counter.get(&w) {
-                Some(n) => {
-                    num_wild = *n;
-                    if num_wild == cards.len() as i32 {
-                        return Hand::FIVE;
-                    }
-                    counter.remove(&w);
+            if let Some(n) = counter.get(&w) {
+                num_wild = *n;
+                if num_wild == cards.len() as i32 {
+                    return Hand::FIVE;
                 }
-                None => (),
+                counter.remove(&w);
             }
         }
         let mut counts: Vec<(char, i32)> =
             counter.iter().map(|kv| (**kv.0, *kv.1)).collect();
-        counts.sort_by(|a, b| b.1.cmp(&a.1));
+        counts.sort_by_key(|c| std::cmp::Reverse(c.1));
         counts[0].1 += num_wild;
         if counts[0].1 == 5 {
             return Hand::FIVE;
@@ -182,22 +189,32 @@ impl Deck {
         self
     }
 }
-fn main() {
-    let f = File::open("input/007.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let deck = Deck::from(&lines);
-    println!("score for this play is {}", deck.score());
-    let wild_deck = deck.set_wild(Some('J'));
-    println!("Jack's wild: {}", wild_deck.score());
+pub struct Day007 {
+    deck: Deck,
+}
+
+impl Solution for Day007 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day007 {
+            deck: Deck::try_from(&aoc::lines(input))?,
+        })
+    }
+
+    fn part1(&self) -> u32 {
+        self.deck.score()
+    }
+
+    fn part2(&self) -> u32 {
+        self.deck.clone().set_wild(Some('J')).score()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE: &str = r#"32T3K 765
 T55J5 684
 KK677 28
@@ -208,7 +225,7 @@ QQQJA 483
     #[test]
     fn test_parse() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines);
+        let deck = Deck::try_from(&lines).unwrap();
         assert_eq!(deck.hands.len(), 5);
         assert_eq!(deck.hands[0].bid, 765);
         assert_eq!(deck.hands[1].bid, 684);
@@ -222,10 +239,21 @@ QQQJA 483
         assert_eq!(deck.hands[2].cards[4], '7');
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let lines = vec!["32T3K 765".to_string(), "T55X5 684".to_string()];
+        let e = Deck::try_from(&lines).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "X"));
+        let e = Hand::try_from(&"KK677 28x".to_string()).err().unwrap();
+        assert_eq!((e.column, e.text.as_str()), (7, "28x"));
+        let e = Hand::try_from(&"KK677".to_string()).err().unwrap();
+        assert_eq!(e.reason, "hand has no bid");
+    }
+
     #[test]
     fn test_classify() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines);
+        let deck = Deck::try_from(&lines).unwrap();
         assert_eq!(Hand::classify(&vec!['1', '2', '3', '4', '5']), Hand::HIGH);
         assert_eq!(Hand::classify(&vec!['4', '4', '3', '3', '4']), Hand::HOUSE);
         assert_eq!(Hand::classify(&vec!['4', '4', '3', '4', '4']), Hand::FOUR);
@@ -289,7 +317,7 @@ QQQJA 483
     #[test]
     fn test_cmp_hand() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines);
+        let deck = Deck::try_from(&lines).unwrap();
         assert!(deck.hands[0] == deck.hands[0]);
         assert!(deck.hands[1] == deck.hands[1]);
         assert!(deck.hands[2] == deck.hands[2]);
@@ -299,15 +327,15 @@ QQQJA 483
         assert!(deck.hands[2] > deck.hands[3]); // KK677 > KTJJT
         assert!(deck.hands[4] > deck.hands[2]); // QQQJA > KK677
         assert!(deck.hands[4] > deck.hands[1]); // QQQJA > T55J5
-        let a = Hand::from(&"KTJJT 220".to_string());
-        let b = Hand::from(&"KJTJT 220".to_string());
+        let a = Hand::try_from(&"KTJJT 220".to_string()).unwrap();
+        let b = Hand::try_from(&"KJTJT 220".to_string()).unwrap();
         assert!(a < b); // KTJJT < KJTJT
     }
 
     #[test]
     fn test_cmp_wild_hand() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines).set_wild(Some('J'));
+        let deck = Deck::try_from(&lines).unwrap().set_wild(Some('J'));
         assert!(deck.hands[0] == deck.hands[0]);
         assert!(deck.hands[1] == deck.hands[1]);
         assert!(deck.hands[2] == deck.hands[2]);
@@ -317,15 +345,15 @@ QQQJA 483
         assert!(deck.hands[2] < deck.hands[3]); // KK677 < KTJJT (KTTTT)
         assert!(deck.hands[4] > deck.hands[2]); // QQQJA > KK677
         assert!(deck.hands[4] > deck.hands[1]); // QQQJA > T55J5
-        let a = Hand::from(&"KTJJT 220".to_string()).set_wild(Some('J'));
-        let b = Hand::from(&"KJTJT 220".to_string()).set_wild(Some('J'));
+        let a = Hand::try_from(&"KTJJT 220".to_string()).unwrap().set_wild(Some('J'));
+        let b = Hand::try_from(&"KJTJT 220".to_string()).unwrap().set_wild(Some('J'));
         assert!(a > b); // KTJJT > KJTJT
     }
 
     #[test]
     fn test_judge() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines);
+        let deck = Deck::try_from(&lines).unwrap();
         let deck = deck.judge();
         assert_eq!(deck.hands[0].bid, 765);
         assert_eq!(deck.hands[1].bid, 220);
@@ -337,14 +365,14 @@ QQQJA 483
     #[test]
     fn test_round() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let deck = Deck::from(&lines);
+        let deck = Deck::try_from(&lines).unwrap();
         assert_eq!(deck.score(), 6440);
     }
 
     #[test]
     fn test_wild_round() {
         let lines = SAMPLE.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let wild_deck = Deck::from(&lines).set_wild(Some('J'));
+        let wild_deck = Deck::try_from(&lines).unwrap().set_wild(Some('J'));
         assert_eq!(wild_deck.score(), 5905);
     }
 }
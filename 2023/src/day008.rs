@@ -0,0 +1,352 @@
+use aoc::math::{crt, find_cycle};
+use aoc::{parallel, parse_line, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Move {
+    Left,
+    Right,
+}
+#[derive(Clone)]
+struct DanceCard {
+    moves: VecDeque<Move>,
+    orininal: Vec<Move>,
+}
+
+#[derive(Clone)]
+struct Map {
+    card: DanceCard,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl TryFrom<&String> for DanceCard {
+    type Error = ParseError;
+
+    fn try_from(line: &String) -> Result<Self, ParseError> {
+        let line = line.trim_end();
+        let v = line
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Move::Left),
+                'R' => Ok(Move::Right),
+                _ => Err(ParseError::new(
+                    "unknown move",
+                    line,
+                    &line[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<Vec<Move>, ParseError>>()?;
+        if v.is_empty() {
+            return Err(ParseError::line("there are no moves", line));
+        }
+        Ok(DanceCard {
+            moves: VecDeque::from(v.clone()),
+            orininal: v,
+        })
+    }
+}
+
+parse_line! {
+    struct Node {
+        key: String,
+        lft: String,
+        rgt: String,
+    } = "{key} = ({lft}, {rgt})";
+}
+
+impl TryFrom<Vec<String>> for Map {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, ParseError> {
+        let mut liter = lines.iter();
+        let card = DanceCard::try_from(liter.next().unwrap_or(&String::new()))?;
+        match liter.next() {
+            Some(blank) if blank.trim().is_empty() => {}
+            other => {
+                let line = other.map_or("", String::as_str);
+                return Err(ParseError::line("expected a blank line", line).below(1));
+            }
+        }
+        let nodes = liter
+            .enumerate()
+            .map(|(i, line)| {
+                let node: Node = line
+                    .trim_end()
+                    .parse()
+                    .map_err(|e: ParseError| e.below(i + 2))?;
+                Ok((node.key, (node.lft, node.rgt)))
+            })
+            .collect::<Result<HashMap<String, (String, String)>, ParseError>>()?;
+        Ok(Map { card, nodes })
+    }
+}
+impl DanceCard {
+    fn next(&mut self) -> Move {
+        let m = self.moves.pop_front().expect("moves is empty!");
+        self.moves.push_back(m);
+        m
+    }
+
+    fn reset(&mut self) {
+        self.moves = VecDeque::from(self.orininal.clone());
+    }
+}
+
+/// Where a ghost is: at a node, about to make the move at some index of the
+/// dance card.
+type Ghost<'a> = (&'a str, usize);
+
+/// When a ghost is on a node ending in `Z`: at each of `early` steps, then at
+/// each of `late` steps plus any number of laps of its loop, which starts
+/// `prefix` steps in and takes `len` steps to go round.
+#[derive(Debug, PartialEq)]
+struct Haunt {
+    prefix: usize,
+    len: usize,
+    early: Vec<usize>,
+    late: Vec<usize>,
+}
+
+impl Haunt {
+    fn at_z(&self, t: usize) -> bool {
+        if t < self.prefix {
+            self.early.contains(&t)
+        } else {
+            self.late
+                .iter()
+                .any(|&z| t >= z && (t - z).is_multiple_of(self.len))
+        }
+    }
+}
+
+impl Map {
+    fn step<'a>(&'a self, &(here, i): &Ghost<'a>) -> Ghost<'a> {
+        let fork = self.nodes.get(here).unwrap();
+        let there = match self.card.orininal[i] {
+            Move::Left => &fork.0,
+            Move::Right => &fork.1,
+        };
+        (there, (i + 1) % self.card.orininal.len())
+    }
+
+    fn haunt(&self, start: &str) -> Haunt {
+        let (prefix, len) = find_cycle((start, 0), |g| self.step(g));
+        let (early, late) = std::iter::successors(Some((start, 0)), |g| Some(self.step(g)))
+            .take(prefix + len)
+            .positions(|(here, _)| here.ends_with('Z'))
+            .partition(|&t| t < prefix);
+        Haunt {
+            prefix,
+            len,
+            early,
+            late,
+        }
+    }
+
+    fn walk(&mut self) -> VecDeque<String> {
+        self.card.reset();
+        self.do_walk("AAA")
+    }
+
+    fn do_walk(&mut self, start: &str) -> VecDeque<String> {
+        let mut path = VecDeque::new();
+        let mut here = start;
+        path.push_back(here.to_owned());
+        while !here.ends_with('Z') {
+            let fork = self.nodes.get(here).unwrap();
+            match self.card.next() {
+                Move::Left => here = &fork.0,
+                Move::Right => here = &fork.1,
+            }
+            path.push_back(here.to_owned());
+        }
+        path
+    }
+
+    /// The first step at which every ghost is on a node ending in `Z`, if
+    /// there ever is one.
+    fn ghost_walk(&self) -> Option<usize> {
+        let starts: Vec<&String> = self.nodes.keys().filter(|key| key.ends_with('A')).collect();
+        let haunts = parallel::map(&starts, |start| self.haunt(start));
+        let settled = haunts.iter().map(|h| h.prefix).max()?;
+        // before every ghost is going round its loop, just look
+        let early = haunts
+            .iter()
+            .flat_map(|h| h.early.iter().copied())
+            .filter(|&t| haunts.iter().all(|h| h.at_z(t)))
+            .min();
+        if early.is_some() {
+            return early;
+        }
+        // after that, each ghost's loop says where it can be when
+        haunts
+            .iter()
+            .map(|h| h.late.iter().map(move |&z| (z as i64, h.len as i64)))
+            .multi_cartesian_product()
+            .filter_map(crt)
+            .map(|(x, m)| {
+                let (x, m) = (x as usize, m as usize);
+                if x < settled {
+                    x + (settled - x).div_ceil(m) * m
+                } else {
+                    x
+                }
+            })
+            .min()
+    }
+}
+pub struct Day008 {
+    map: Map,
+}
+
+impl Solution for Day008 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day008 {
+            map: Map::try_from(aoc::lines(input))?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.map.clone().walk().len() - 1
+    }
+
+    fn part2(&self) -> usize {
+        self.map.ghost_walk().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE1: &str = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+"#;
+    const SAMPLE2: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)    
+"#;
+    const GHOSTMAP: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"#;
+
+    #[test]
+    fn test_parse() {
+        let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let map = Map::try_from(lines).unwrap();
+        assert_eq!(map.card.moves.len(), 2);
+        assert_eq!(map.nodes.len(), 7);
+        assert_eq!(map.nodes.get("CCC").unwrap().0, "ZZZ");
+        assert_eq!(map.nodes.get("CCC").unwrap().1, "GGG");
+        let lines = SAMPLE2.replace("(AAA, ZZZ)", "(AAA ZZZ)");
+        let lines = lines.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let e = Map::try_from(lines).err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.reason.as_str()),
+            (4, 8, "expected `, `")
+        );
+        let e = Map::try_from(vec!["RXL".to_string()]).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 2, "X"));
+        let e = Map::try_from(vec![]).err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (1, "there are no moves"));
+        let e = Map::try_from(vec!["LR".to_string()]).err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (2, "expected a blank line"));
+    }
+
+    #[test]
+    fn test_card() {
+        let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut map = Map::try_from(lines).unwrap();
+        assert_eq!(map.card.next(), Move::Right);
+        assert_eq!(map.card.next(), Move::Left);
+        assert_eq!(map.card.next(), Move::Right);
+        map.card.reset();
+        assert_eq!(map.card.next(), Move::Right);
+    }
+
+    #[test]
+    fn test_walk1() {
+        let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut map = Map::try_from(lines).unwrap();
+        let path = map.walk();
+        assert_eq!(path.len() - 1, 2);
+    }
+
+    #[test]
+    fn test_walk2() {
+        let lines = SAMPLE2.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut map = Map::try_from(lines).unwrap();
+        let path = map.walk();
+        assert_eq!(path.len() - 1, 6);
+    }
+
+    #[test]
+    fn test_ghostwalk() {
+        let lines = GHOSTMAP.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let map = Map::try_from(lines).unwrap();
+        assert_eq!(map.ghost_walk(), Some(6));
+    }
+
+    #[test]
+    fn test_haunt() {
+        let lines = GHOSTMAP.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+        let map = Map::try_from(lines).unwrap();
+        let haunt = map.haunt("22A");
+        assert_eq!(
+            haunt,
+            Haunt {
+                prefix: 1,
+                len: 6,
+                early: vec![],
+                late: vec![3, 6],
+            }
+        );
+        assert!(!haunt.at_z(2));
+        assert!(haunt.at_z(9));
+        assert!(haunt.at_z(12));
+        assert!(!haunt.at_z(13));
+    }
+
+    #[test]
+    fn test_offset_ghosts() {
+        // one ghost is at Z on step 2 and then every 3 steps, the other on
+        // odd steps only, so they first meet at step 5
+        let lines = r#"L
+
+AAA = (BBB, BBB)
+BBB = (CCZ, CCZ)
+CCZ = (DDD, DDD)
+DDD = (EEE, EEE)
+EEE = (CCZ, CCZ)
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+"#
+        .lines()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+        let map = Map::try_from(lines).unwrap();
+        assert_eq!(map.ghost_walk(), Some(5));
+    }
+}
@@ -1,6 +1,4 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use aoc::{ParseError, Solution};
 
 struct Dataset {
     sensors: Vec<Sensor>,
@@ -10,20 +8,34 @@ struct Sensor {
     data: Vec<i32>,
 }
 
-impl From<&String> for Sensor {
-    fn from(line: &String) -> Self {
+impl TryFrom<&String> for Sensor {
+    type Error = ParseError;
+
+    fn try_from(line: &String) -> Result<Self, ParseError> {
         let data = line
-            .split(' ')
-            .map(|n| n.parse::<i32>().expect("malformed integer"))
-            .collect();
-        Sensor { data }
+            .split_whitespace()
+            .map(|n| {
+                n.parse::<i32>()
+                    .map_err(|_| ParseError::new("reading is not a number", line, n))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if data.is_empty() {
+            return Err(ParseError::line("sensor has no readings", line));
+        }
+        Ok(Sensor { data })
     }
 }
 
-impl From<Vec<String>> for Dataset {
-    fn from(lines: Vec<String>) -> Self {
-        let histories = lines.iter().map(Sensor::from).collect();
-        Dataset { sensors: histories }
+impl TryFrom<Vec<String>> for Dataset {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, ParseError> {
+        let histories = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Sensor::try_from(line).map_err(|e| e.below(i)))
+            .collect::<Result<_, _>>()?;
+        Ok(Dataset { sensors: histories })
     }
 }
 
@@ -69,22 +81,32 @@ impl Dataset {
     }
 }
 
-fn main() {
-    let f = File::open("input/009.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let dataset = Dataset::from(lines);
-    let analysis = dataset.analyze();
-    println!("the result of prediciton is: {}", analysis.0);
-    println!("the result of revision is: {}", analysis.1);
+pub struct Day009 {
+    dataset: Dataset,
+}
+
+impl Solution for Day009 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day009 {
+            dataset: Dataset::try_from(aoc::lines(input))?,
+        })
+    }
+
+    fn part1(&self) -> i32 {
+        self.dataset.analyze().0
+    }
+
+    fn part2(&self) -> i32 {
+        self.dataset.analyze().1
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const SAMPLE1: &str = r#"0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45"#;
@@ -92,7 +114,7 @@ mod tests {
     #[test]
     fn test_parse() {
         let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let dataset = Dataset::from(lines);
+        let dataset = Dataset::try_from(lines).unwrap();
         assert_eq!(dataset.sensors.len(), 3);
         assert_eq!(dataset.sensors[0].data.len(), 6);
         assert_eq!(dataset.sensors[1].data.len(), 6);
@@ -100,12 +122,17 @@ mod tests {
         assert_eq!(dataset.sensors[1].data[0], 1);
         assert_eq!(dataset.sensors[1].data[3], 10);
         assert_eq!(dataset.sensors[1].data[5], 21);
+        let lines = vec!["1 2 3".to_string(), "4 x 6".to_string()];
+        let e = Dataset::try_from(lines).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+        let e = Dataset::try_from(vec![String::new()]).err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (1, "sensor has no readings"));
     }
 
     #[test]
     fn test_predict() {
         let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let dataset = Dataset::from(lines);
+        let dataset = Dataset::try_from(lines).unwrap();
         assert_eq!(dataset.sensors[0].predict().0, 18);
         assert_eq!(dataset.sensors[1].predict().0, 28);
         assert_eq!(dataset.sensors[2].predict().0, 68);
@@ -114,7 +141,7 @@ mod tests {
     #[test]
     fn test_revision() {
         let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let dataset = Dataset::from(lines);
+        let dataset = Dataset::try_from(lines).unwrap();
         assert_eq!(dataset.sensors[0].predict().1, -3);
         assert_eq!(dataset.sensors[1].predict().1, 0);
         assert_eq!(dataset.sensors[2].predict().1, 5);
@@ -123,7 +150,7 @@ mod tests {
     #[test]
     fn test_analyze() {
         let lines = SAMPLE1.lines().map(|s| s.to_string()).collect::<Vec<_>>();
-        let dataset = Dataset::from(lines);
+        let dataset = Dataset::try_from(lines).unwrap();
         assert_eq!(dataset.analyze(), (114, 2));
     }
 }
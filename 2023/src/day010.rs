@@ -0,0 +1,413 @@
+use aoc::image::{Image, BLACK, GREY, ORANGE, RED};
+use aoc::{Direction, Grid, ParseError, Point2, Pos, Solution};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::str::FromStr;
+use Direction::{East, North, South, West};
+
+type Coord = Point2<i32>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Tile {
+    Ground,
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Start,
+}
+
+impl Tile {
+    /// The two sides a pipe leaves by, if it is a pipe.
+    fn exits(&self) -> &'static [Direction] {
+        match self {
+            Tile::NS => &[North, South],
+            Tile::EW => &[East, West],
+            Tile::NE => &[North, East],
+            Tile::NW => &[North, West],
+            Tile::SW => &[South, West],
+            Tile::SE => &[South, East],
+            Tile::Ground | Tile::Start => &[],
+        }
+    }
+}
+
+lazy_static! {
+    static ref TILE_MAP: HashMap<char, Tile> = HashMap::from([
+        ('|', Tile::NS),
+        ('-', Tile::EW),
+        ('L', Tile::NE),
+        ('J', Tile::NW),
+        ('F', Tile::SE),
+        ('7', Tile::SW),
+        ('S', Tile::Start),
+        ('.', Tile::Ground),
+    ]);
+}
+#[derive(Clone)]
+struct Map {
+    tiles: Grid<Tile>,
+    start: Coord,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::try_parse(input, |c| TILE_MAP.get(&c).copied())?;
+        let start = tiles.position(|t| *t == Tile::Start).ok_or_else(|| {
+            let (i, last) = input.lines().enumerate().last().unwrap_or((0, ""));
+            ParseError::line("map ends without a start", last).below(i)
+        })?;
+        let start = Coord::new(start.0 as i32, start.1 as i32);
+        Ok(Map { tiles, start })
+    }
+}
+
+impl Map {
+    fn tile(&self, c: Coord) -> Tile {
+        *self.tiles.get(c.x, c.y).expect("walked off the map")
+    }
+
+    fn leads(&self, c: Coord, from: Direction) -> bool {
+        self.tiles
+            .get(c.x, c.y)
+            .is_some_and(|t| t.exits().contains(&from))
+    }
+
+    fn find_start(&self) -> Option<(Direction, Coord)> {
+        let here = self.start;
+        Direction::ALL
+            .into_iter()
+            .map(|d| (d.opposite(), here + d.offset()))
+            .find(|&(from, next)| self.leads(next, from))
+    }
+
+    fn walk_loop(&self) -> Vec<Coord> {
+        let mut path = Vec::new();
+        path.push(self.start);
+        let (mut from, mut here) = self.find_start().expect("there was no path out of start");
+        while here != self.start {
+            path.push(here);
+            let exits = self.tile(here).exits();
+            if !exits.contains(&from) {
+                panic!("pipes do not match");
+            }
+            let to = *exits.iter().find(|&&d| d != from).unwrap();
+            (from, here) = (to.opposite(), here + to.offset());
+        }
+        path
+    }
+
+    fn resolve_start(&mut self, path: &[Coord]) {
+        let first = path[1];
+        let last = path.last().expect("path should be longer than 0");
+        let a = first - self.start;
+        let b = *last - self.start;
+        let start_tile: Tile;
+        if a.x == 0 && b.x == 0 {
+            start_tile = Tile::NS;
+        } else if a.y == 0 && b.y == 0 {
+            start_tile = Tile::EW;
+        } else {
+            let d = a + b;
+            start_tile = match (d.x, d.y) {
+                (1, -1) => Tile::NE,
+                (1, 1) => Tile::SE,
+                (-1, 1) => Tile::SW,
+                (-1, -1) => Tile::NW,
+                _ => panic!("unknown pipe configuration at start"),
+            }
+        }
+        *self
+            .tiles
+            .get_mut(self.start.x, self.start.y)
+            .expect("start is on the map") = start_tile;
+    }
+
+    fn score(tile: &Tile) -> usize {
+        match tile {
+            Tile::Ground => 0,
+            Tile::NS => 1,
+            Tile::EW => 1,
+            Tile::NW => 1,
+            Tile::SE => 1,
+            Tile::NE => 2, // corner cut
+            Tile::SW => 2, // corner cut
+            Tile::Start => panic!("start should have been cleared by this point"),
+        }
+    }
+
+    fn contained_in(&self, path: Vec<Coord>) -> Vec<Coord> {
+        let mut windings = self.tiles.map(Map::score);
+        let w = self.tiles.width() as i32;
+        let h = self.tiles.height() as i32;
+        for i in 0..w {
+            let mut num_windings = 0;
+            for j in 0..h {
+                let x = i + j;
+                let y = j;
+                if let Some(tile) = self.tiles.get(x, y) {
+                    if path.contains(&Coord::new(x, y)) {
+                        num_windings += Map::score(tile);
+                    }
+                    windings[(x as usize, y as usize)] = num_windings;
+                }
+            }
+        }
+        for j in 0..h {
+            let mut num_windings = 0;
+            for i in 0..w {
+                let x = i;
+                let y = i + j;
+                if let Some(tile) = self.tiles.get(x, y) {
+                    if path.contains(&Coord::new(x, y)) {
+                        num_windings += Map::score(tile);
+                    }
+                    windings[(x as usize, y as usize)] = num_windings;
+                }
+            }
+        }
+        windings
+            .indexed()
+            .map(|((i, j), w)| (Coord::new(i as i32, j as i32), w))
+            .filter(|(p, w)| *w % 2 == 1 && !path.contains(p))
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    /// The pipes three pixels to a tile so their shapes show, with the loop
+    /// in red over the rest in grey, and what it encloses filled in orange.
+    fn picture(&self) -> Image {
+        let mut map = self.clone();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        let enclosed = map.contained_in(path.clone());
+        let (w, h) = (map.tiles.width(), map.tiles.height());
+        let mut image = Image::new(3 * w, 3 * h, BLACK);
+        let pipe = |c: Coord| -> Vec<Pos> {
+            let exits = map.tile(c).exits();
+            if exits.is_empty() {
+                return Vec::new();
+            }
+            let middle = Coord::new(3 * c.x + 1, 3 * c.y + 1);
+            std::iter::once(middle)
+                .chain(exits.iter().map(|d| middle + d.offset()))
+                .map(|p| (p.x as usize, p.y as usize))
+                .collect()
+        };
+        for (x, y) in map.tiles.positions() {
+            image.overlay(pipe(Coord::new(x as i32, y as i32)), GREY);
+        }
+        for c in path.iter() {
+            image.overlay(pipe(*c), RED);
+        }
+        for c in enclosed {
+            let (x, y) = (3 * c.x as usize, 3 * c.y as usize);
+            image.overlay((0..9).map(|i| (x + i % 3, y + i / 3)), ORANGE);
+        }
+        image
+    }
+}
+pub struct Day010 {
+    map: Map,
+}
+
+impl Solution for Day010 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day010 {
+            map: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.map.walk_loop().len() / 2
+    }
+
+    fn part2(&self) -> usize {
+        let mut map = self.map.clone();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        map.contained_in(path).len()
+    }
+
+    fn picture(&self) -> Option<Image> {
+        Some(self.map.picture())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE1: &str = r#"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+"#;
+    const SAMPLE2: &str = r#"7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+"#;
+
+    #[test]
+    fn test_parse1() {
+        let map = Map::from_str(SAMPLE1).unwrap();
+        assert_eq!(map.tiles[(0, 0)], Tile::EW);
+        assert_eq!(map.tiles[(4, 4)], Tile::SE);
+        assert_eq!(map.tiles[(1, 1)], Tile::Start);
+        assert_eq!(map.tiles[(4, 0)], Tile::SW);
+        assert_eq!(map.start, Coord::new(1, 1));
+        assert_eq!((map.tiles.width(), map.tiles.height()), (5, 5));
+    }
+
+    #[test]
+    fn test_parse2() {
+        let map = Map::from_str(SAMPLE2).unwrap();
+        assert_eq!(map.start, Coord::new(0, 2));
+        assert_eq!((map.tiles.width(), map.tiles.height()), (5, 5));
+        assert_eq!(map.tiles[(0, 2)], Tile::Start);
+        let e = Map::from_str(&SAMPLE2.replace('S', ".")).err().unwrap();
+        assert_eq!((e.line, e.reason.as_str()), (5, "map ends without a start"));
+        let e = Map::from_str("..\n.x").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_find_start1() {
+        let map = Map::from_str(SAMPLE1).unwrap();
+        assert_eq!(map.find_start().unwrap().0, West);
+        assert_eq!(map.find_start().unwrap().1, Coord::new(2, 1));
+    }
+
+    #[test]
+    fn test_find_start2() {
+        let map = Map::from_str(SAMPLE2).unwrap();
+        assert_eq!(map.find_start().unwrap().0, West);
+        assert_eq!(map.find_start().unwrap().1, Coord::new(1, 2));
+    }
+
+    #[test]
+    fn test_walk_loop1() {
+        let map = Map::from_str(SAMPLE1).unwrap();
+        let path = map.walk_loop();
+        assert_eq!(path.len(), 8);
+    }
+
+    #[test]
+    fn test_walk_loop2() {
+        let map = Map::from_str(SAMPLE2).unwrap();
+        let path = map.walk_loop();
+        assert_eq!(path.len(), 16);
+    }
+
+    const SAMPLE3: &str = r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+"#;
+
+    #[test]
+    fn test_resolve_start_3() {
+        let mut map = Map::from_str(SAMPLE3).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        assert_eq!(map.tile(map.start), Tile::SE);
+    }
+
+    #[test]
+    fn test_contain_3() {
+        let mut map = Map::from_str(SAMPLE3).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        let area = map.contained_in(path).len();
+        assert_eq!(area, 4);
+    }
+
+    const SAMPLE4: &str = r#".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+"#;
+
+    #[test]
+    fn test_resolve_start_4() {
+        let mut map = Map::from_str(SAMPLE4).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        assert_eq!(map.tile(map.start), Tile::SE);
+    }
+
+    #[test]
+    fn test_contain_4() {
+        let mut map = Map::from_str(SAMPLE4).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        let area = map.contained_in(path).len();
+        assert_eq!(area, 8);
+    }
+
+    const SAMPLE5: &str = r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+"#;
+
+    #[test]
+    fn test_resolve_start_5() {
+        let mut map = Map::from_str(SAMPLE5).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        assert_eq!(map.tile(map.start), Tile::SW);
+    }
+
+    #[test]
+    fn test_contain_5() {
+        let mut map = Map::from_str(SAMPLE5).unwrap();
+        let path = map.walk_loop();
+        map.resolve_start(&path);
+        let area = map.contained_in(path).len();
+        assert_eq!(area, 10);
+    }
+
+    #[test]
+    fn test_picture() {
+        let image = Map::from_str(SAMPLE1).unwrap().picture();
+        assert_eq!((image.width(), image.height()), (15, 15));
+        // the start turns out to be an F, so the loop leaves it east and south
+        assert_eq!(image.get((4, 4)), Some(RED));
+        assert_eq!(image.get((5, 4)), Some(RED));
+        assert_eq!(image.get((4, 5)), Some(RED));
+        assert_eq!(image.get((3, 4)), Some(BLACK));
+        // the - in the corner isn't part of it
+        assert_eq!(image.get((1, 1)), Some(GREY));
+        assert_eq!(image.get((1, 0)), Some(BLACK));
+        // and the middle tile is inside it
+        assert_eq!(image.get((6, 6)), Some(ORANGE));
+        assert_eq!(image.get((8, 8)), Some(ORANGE));
+    }
+}
@@ -1,90 +1,88 @@
+use aoc::{ParseError, Point2, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 type Integer = i64;
-type Coord = (Integer, Integer);
+type Coord = Point2<Integer>;
 
 fn find_galaxies(lines: Vec<String>) -> Vec<Coord> {
     let mut galaxies = Vec::new();
     for (j, line) in lines.iter().enumerate() {
         for (i, c) in line.chars().enumerate() {
             if c == '#' {
-                galaxies.push((i as Integer, j as Integer));
+                galaxies.push(Coord::new(i as Integer, j as Integer));
             }
         }
     }
     galaxies
 }
 
-fn inflate(galaxies: &Vec<Coord>, expansion_ratio: Integer) -> Vec<Coord> {
+fn inflate(galaxies: &[Coord], expansion_ratio: Integer) -> Vec<Coord> {
     // inflate in x direction
     let igroups: HashMap<Integer, Vec<Coord>> =
-        galaxies.to_owned().into_iter().into_group_map_by(|a| a.0);
+        galaxies.iter().copied().into_group_map_by(|a| a.x);
     let mut last = -1;
     let mut num_empty = 0;
     let mut inflated = Vec::new();
     for key in igroups.keys().sorted() {
         num_empty += key - last - 1;
         last = *key;
-        for galaxy in igroups[&key].iter() {
-            inflated.push((galaxy.0 + num_empty * expansion_ratio, galaxy.1));
+        for galaxy in igroups[key].iter() {
+            inflated.push(Coord::new(galaxy.x + num_empty * expansion_ratio, galaxy.y));
         }
     }
     // inflate in y direction
     let jgroups: HashMap<Integer, Vec<Coord>> =
-        inflated.to_owned().into_iter().into_group_map_by(|a| a.1);
+        inflated.iter().copied().into_group_map_by(|a| a.y);
     inflated.clear();
     let mut last = -1;
     let mut num_empty = 0;
     for key in jgroups.keys().sorted() {
         num_empty += key - last - 1;
         last = *key;
-        for galaxy in jgroups[&key].iter() {
-            inflated.push((galaxy.0, galaxy.1 + num_empty * expansion_ratio));
+        for galaxy in jgroups[key].iter() {
+            inflated.push(Coord::new(galaxy.x, galaxy.y + num_empty * expansion_ratio));
         }
     }
     inflated
 }
 
-fn compute_distances(galaxies: &Vec<Coord>) -> Vec<Integer> {
+fn compute_distances(galaxies: &[Coord]) -> Vec<Integer> {
     let mut distances = Vec::new();
     for (i, a) in galaxies.iter().enumerate() {
         for b in galaxies.iter().skip(i + 1) {
-            distances.push((a.0 - b.0).abs() + (a.1 - b.1).abs());
+            distances.push(a.manhattan(b));
         }
     }
     distances
 }
 
-fn main() {
-    let f = File::open("input/011.txt").expect("File Error");
-    let reader = BufReader::new(f);
-    let lines: Vec<_> = reader
-        .lines()
-        .map(|l| l.expect("Could not read line"))
-        .collect();
-    let galaxies = find_galaxies(lines);
-    let inflated = inflate(&galaxies, 1);
-    let distances = compute_distances(&inflated);
-    println!(
-        "sum of intergalactic ditances is {}",
-        distances.iter().sum::<Integer>()
-    );
-
-    let inflated = inflate(&galaxies, 999999);
-    let distances = compute_distances(&inflated);
-    println!(
-        "sum of hyper-inflated ditances is {}",
-        distances.iter().sum::<Integer>()
-    );
+pub struct Day011 {
+    galaxies: Vec<Coord>,
+}
+
+impl Solution for Day011 {
+    type Part1 = Integer;
+    type Part2 = Integer;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day011 {
+            galaxies: find_galaxies(aoc::lines(input)),
+        })
+    }
+
+    fn part1(&self) -> Integer {
+        compute_distances(&inflate(&self.galaxies, 1)).iter().sum()
+    }
+
+    fn part2(&self) -> Integer {
+        compute_distances(&inflate(&self.galaxies, 999999)).iter().sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     const CONTRACTED: &str = r#"...#......
 .......#..
 #.........
@@ -131,6 +129,9 @@ mod tests {
                 (0, 9),
                 (4, 9),
             ]
+            .into_iter()
+            .map(Coord::from)
+            .collect::<Vec<_>>()
         );
     }
 
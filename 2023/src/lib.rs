@@ -0,0 +1,41 @@
+//! Advent of Code 2023, one module per day, all registered in `calendar()`.
+
+use aoc::{Calendar, Day};
+
+mod day001;
+mod day002;
+mod day003;
+mod day004;
+mod day005;
+mod day006;
+mod day007;
+mod day008;
+mod day009;
+mod day010;
+mod day011;
+
+pub fn calendar() -> Calendar {
+    Calendar::new(
+        2023,
+        env!("CARGO_MANIFEST_DIR"),
+        vec![
+            Day::new::<day001::Day001>(1),
+            Day::new::<day002::Day002>(2),
+            Day::new::<day003::Day003>(3),
+            Day::new::<day004::Day004>(4),
+            Day::new::<day005::Day005>(5),
+            Day::new::<day006::Day006>(6),
+            Day::new::<day007::Day007>(7),
+            Day::new::<day008::Day008>(8),
+            Day::new::<day009::Day009>(9),
+            Day::new::<day010::Day010>(10),
+            Day::new::<day011::Day011>(11),
+        ],
+    )
+}
+
+/// One test per part of every `input/NNN-sample-K.txt`, see `aoc::sample`.
+#[cfg(test)]
+mod samples {
+    include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+}
@@ -0,0 +1,328 @@
+//! Simulations that move a whole board on one step at a time, with the rules
+//! written as closures over the neighbourhood of each place. Every place
+//! either holds a cell or is empty: sparse boards only store what is there,
+//! dense ones are a `Grid` of `Option`s.
+
+use crate::grid::Grid;
+use crate::point::{Direction8, Point2};
+use std::collections::{HashMap, HashSet};
+
+type Coord = Point2<i32>;
+
+/// Somewhere cells live.
+pub trait Board {
+    type Cell: Clone + PartialEq;
+
+    /// The cell at `p`, or `None` when `p` is empty or off the board.
+    fn get(&self, p: Coord) -> Option<&Self::Cell>;
+
+    /// Every place that holds a cell.
+    fn occupied(&self) -> Vec<Coord>;
+
+    /// Every place a rule has to be asked about to work out the next step:
+    /// the whole of a dense board, but only the cells of a sparse one and the
+    /// places around them, since nothing can appear out of nowhere.
+    fn candidates(&self) -> Vec<Coord>;
+
+    /// Puts `cell` at `p`, or empties it.
+    fn set(&mut self, p: Coord, cell: Option<Self::Cell>);
+}
+
+fn around(occupied: Vec<Coord>) -> Vec<Coord> {
+    let mut places: HashSet<Coord> = occupied.iter().flat_map(|p| p.neighbours8()).collect();
+    places.extend(occupied);
+    places.into_iter().collect()
+}
+
+impl Board for HashSet<Coord> {
+    type Cell = ();
+
+    fn get(&self, p: Coord) -> Option<&()> {
+        self.contains(&p).then_some(&())
+    }
+
+    fn occupied(&self) -> Vec<Coord> {
+        self.iter().copied().collect()
+    }
+
+    fn candidates(&self) -> Vec<Coord> {
+        around(self.occupied())
+    }
+
+    fn set(&mut self, p: Coord, cell: Option<()>) {
+        match cell {
+            Some(()) => self.insert(p),
+            None => self.remove(&p),
+        };
+    }
+}
+
+impl<T: Clone + PartialEq> Board for HashMap<Coord, T> {
+    type Cell = T;
+
+    fn get(&self, p: Coord) -> Option<&T> {
+        HashMap::get(self, &p)
+    }
+
+    fn occupied(&self) -> Vec<Coord> {
+        self.keys().copied().collect()
+    }
+
+    fn candidates(&self) -> Vec<Coord> {
+        around(self.occupied())
+    }
+
+    fn set(&mut self, p: Coord, cell: Option<T>) {
+        match cell {
+            Some(cell) => self.insert(p, cell),
+            None => self.remove(&p),
+        };
+    }
+}
+
+impl<T: Clone + PartialEq> Board for Grid<Option<T>> {
+    type Cell = T;
+
+    fn get(&self, p: Coord) -> Option<&T> {
+        Grid::get(self, p.x, p.y)?.as_ref()
+    }
+
+    fn occupied(&self) -> Vec<Coord> {
+        self.indexed()
+            .filter(|(_, cell)| cell.is_some())
+            .map(|((x, y), _)| Coord::new(x as i32, y as i32))
+            .collect()
+    }
+
+    fn candidates(&self) -> Vec<Coord> {
+        self.positions()
+            .map(|(x, y)| Coord::new(x as i32, y as i32))
+            .collect()
+    }
+
+    fn set(&mut self, p: Coord, cell: Option<T>) {
+        *self.get_mut(p.x, p.y).expect("cells stay on the board") = cell;
+    }
+}
+
+/// What a rule gets to look at: the board as it was before the step, seen
+/// from one place on it.
+pub struct Neighbourhood<'a, B> {
+    board: &'a B,
+    /// The place the rule is deciding about.
+    pub at: Coord,
+    /// How many steps the board has taken so far.
+    pub steps: usize,
+}
+
+impl<'a, B: Board> Neighbourhood<'a, B> {
+    /// The whole board, for rules that look further than next door.
+    pub fn board(&self) -> &'a B {
+        self.board
+    }
+
+    /// The cell in the middle, if there is one.
+    pub fn centre(&self) -> Option<&'a B::Cell> {
+        self.board.get(self.at)
+    }
+
+    /// The cell `offset` away from the middle.
+    pub fn get(&self, offset: Coord) -> Option<&'a B::Cell> {
+        self.board.get(self.at + offset)
+    }
+
+    /// The cell next door in direction `d`.
+    pub fn look(&self, d: Direction8) -> Option<&'a B::Cell> {
+        self.get(d.offset())
+    }
+
+    /// The cells next door, clockwise from north, skipping empty places.
+    pub fn neighbours8(&self) -> impl Iterator<Item = &'a B::Cell> + '_ {
+        self.at.neighbours8().filter_map(|p| self.board.get(p))
+    }
+}
+
+/// A board and how many steps it has taken.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Automaton<B> {
+    pub board: B,
+    pub steps: usize,
+}
+
+impl<B: Board> Automaton<B> {
+    pub fn new(board: B) -> Self {
+        Automaton { board, steps: 0 }
+    }
+
+    fn neighbourhood(&self, at: Coord) -> Neighbourhood<'_, B> {
+        Neighbourhood {
+            board: &self.board,
+            at,
+            steps: self.steps,
+        }
+    }
+
+    /// Moves every place on at once to whatever `rule` says it holds next,
+    /// and says whether anything changed.
+    pub fn step<F>(&mut self, mut rule: F) -> bool
+    where
+        F: FnMut(&Neighbourhood<B>) -> Option<B::Cell>,
+    {
+        let changes: Vec<(Coord, Option<B::Cell>)> = self
+            .board
+            .candidates()
+            .into_iter()
+            .map(|p| (p, rule(&self.neighbourhood(p))))
+            .filter(|(p, cell)| self.board.get(*p) != cell.as_ref())
+            .collect();
+        let changed = !changes.is_empty();
+        for (p, cell) in changes {
+            self.board.set(p, cell);
+        }
+        self.steps += 1;
+        changed
+    }
+
+    /// Lets every cell propose a place to move to, then moves all the ones
+    /// that can go at once, and says whether any did. A move is blocked when
+    /// another cell proposes the same place, or when the place is taken by a
+    /// cell that is not moving out of it; blocked cells stay where they are.
+    pub fn propose<F>(&mut self, mut proposal: F) -> bool
+    where
+        F: FnMut(&Neighbourhood<B>) -> Option<Coord>,
+    {
+        let proposals: Vec<(Coord, Coord)> = self
+            .board
+            .occupied()
+            .into_iter()
+            .filter_map(|p| Some((p, proposal(&self.neighbourhood(p))?)))
+            .filter(|(from, to)| from != to)
+            .collect();
+        let mut claims: HashMap<Coord, usize> = HashMap::new();
+        for (_, to) in &proposals {
+            *claims.entry(*to).or_default() += 1;
+        }
+        let mut moving: HashMap<Coord, Coord> = proposals
+            .into_iter()
+            .filter(|(_, to)| claims[to] == 1)
+            .collect();
+        // a cell that cannot move can block the one behind it, and so on
+        loop {
+            let blocked: Vec<Coord> = moving
+                .iter()
+                .filter(|(_, to)| self.board.get(**to).is_some() && !moving.contains_key(to))
+                .map(|(from, _)| *from)
+                .collect();
+            if blocked.is_empty() {
+                break;
+            }
+            for from in blocked {
+                moving.remove(&from);
+            }
+        }
+        let cells: Vec<(Coord, Option<B::Cell>)> = moving
+            .iter()
+            .map(|(from, to)| (*to, self.board.get(*from).cloned()))
+            .collect();
+        for from in moving.keys() {
+            self.board.set(*from, None);
+        }
+        for (to, cell) in cells {
+            self.board.set(to, cell);
+        }
+        self.steps += 1;
+        !moving.is_empty()
+    }
+
+    /// Takes steps with `round` for as long as they change anything, and
+    /// returns how many steps the board has taken by the time one doesn't.
+    pub fn until_fixpoint<F: FnMut(&mut Self) -> bool>(&mut self, mut round: F) -> usize {
+        while round(self) {}
+        self.steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(n: &Neighbourhood<HashSet<Coord>>) -> Option<()> {
+        match (n.centre(), n.neighbours8().count()) {
+            (Some(()), 2 | 3) | (None, 3) => Some(()),
+            _ => None,
+        }
+    }
+
+    fn cells(cells: &[(i32, i32)]) -> HashSet<Coord> {
+        cells.iter().map(|&c| Coord::from(c)).collect()
+    }
+
+    #[test]
+    fn test_sparse_step() {
+        let mut blinker = Automaton::new(cells(&[(0, 1), (1, 1), (2, 1)]));
+        assert!(blinker.step(life));
+        assert_eq!(blinker.board, cells(&[(1, 0), (1, 1), (1, 2)]));
+        assert!(blinker.step(life));
+        assert_eq!(blinker.board, cells(&[(0, 1), (1, 1), (2, 1)]));
+        assert_eq!(blinker.steps, 2);
+
+        let mut block = Automaton::new(cells(&[(0, 0), (0, 1), (1, 0), (1, 1)]));
+        assert_eq!(block.until_fixpoint(|a| a.step(life)), 1);
+        assert_eq!(block.board.len(), 4);
+    }
+
+    #[test]
+    fn test_dense_step() {
+        // sand falls one place a step until it lands on something
+        let board = Grid::parse(".o.\n...\n.#.\n...", |c| match c {
+            'o' => Some('o'),
+            '#' => Some('#'),
+            _ => None,
+        });
+        let mut sand = Automaton::new(board);
+        let fall = |n: &Neighbourhood<Grid<Option<char>>>| {
+            let above = n.get(Coord::new(0, -1));
+            let below = n.get(Coord::new(0, 1));
+            match n.centre() {
+                Some('o') if below.is_none() && n.at.y < 3 => None,
+                None if above == Some(&'o') => Some('o'),
+                cell => cell.copied(),
+            }
+        };
+        assert_eq!(sand.until_fixpoint(|a| a.step(fall)), 2);
+        assert_eq!(Board::get(&sand.board, Coord::new(1, 1)), Some(&'o'));
+        assert_eq!(sand.board.occupied().len(), 2);
+    }
+
+    #[test]
+    fn test_propose() {
+        // everyone heads east, and whoever is first in line is stuck
+        let mut queue = Automaton::new(cells(&[(0, 0), (1, 0), (2, 0), (5, 0)]));
+        let east = |n: &Neighbourhood<HashSet<Coord>>| {
+            (n.at.x < 6).then(|| n.at + Direction8::East.offset())
+        };
+        assert!(queue.propose(east));
+        assert_eq!(queue.board, cells(&[(1, 0), (2, 0), (3, 0), (6, 0)]));
+        assert_eq!(queue.until_fixpoint(|a| a.propose(east)), 4);
+        assert_eq!(queue.board, cells(&[(3, 0), (4, 0), (5, 0), (6, 0)]));
+
+        // two that want the same place both stay put
+        let mut pair = Automaton::new(cells(&[(0, 0), (2, 0)]));
+        assert!(!pair.propose(|_| Some(Coord::new(1, 0))));
+        assert_eq!(pair.board, cells(&[(0, 0), (2, 0)]));
+        assert_eq!(pair.steps, 1);
+    }
+
+    #[test]
+    fn test_propose_carries_cells() {
+        let mut board = HashMap::new();
+        board.insert(Coord::new(0, 0), 'a');
+        board.insert(Coord::new(1, 0), 'b');
+        let mut swap = Automaton::new(board);
+        // a train moves as one, even when the front is leaving the back's place
+        assert!(swap.propose(|n| Some(n.at + Coord::new(1, 0))));
+        assert_eq!(swap.board.get(&Coord::new(1, 0)), Some(&'a'));
+        assert_eq!(swap.board.get(&Coord::new(2, 0)), Some(&'b'));
+        assert_eq!(swap.board.len(), 2);
+    }
+}
@@ -0,0 +1,182 @@
+//! Where puzzle inputs are kept. Each year's inputs normally live next to its
+//! crate in `input/`, but `AOC_INPUT_DIR` can point the whole lot somewhere
+//! else, laid out as `{year}/{day:03}.txt`. An input that isn't there yet is
+//! asked for from a `Fetcher`, if there is one, and kept for next time.
+
+use crate::input::{load, InputError};
+use crate::Calendar;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// The environment variable that moves every year's inputs into one place.
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+
+/// Somewhere a missing input can be got from.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String>;
+}
+
+/// Hands out inputs from a directory laid out the way `AOC_INPUT_DIR` is,
+/// such as a checkout of inputs shared between machines.
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        DirFetcher { dir: dir.into() }
+    }
+}
+
+fn layout(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{day:03}.txt"))
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, year: u16, day: u8) -> io::Result<String> {
+        fs::read_to_string(layout(&self.dir, year, day))
+    }
+}
+
+/// Finds the input file for a `(year, day)`, fetching it first if need be.
+pub struct InputCache {
+    dir: Option<PathBuf>,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    /// Keeps inputs under `dir` when given, otherwise in each year's own
+    /// `input/` directory. Nothing is fetched until a `Fetcher` is added.
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        InputCache { dir, fetcher: None }
+    }
+
+    /// The cache `AOC_INPUT_DIR` asks for.
+    pub fn from_env() -> Self {
+        InputCache::new(env::var_os(INPUT_DIR).map(PathBuf::from))
+    }
+
+    pub fn with_fetcher<F: Fetcher + 'static>(mut self, fetcher: F) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn path(&self, calendar: &Calendar, day: u8) -> PathBuf {
+        match &self.dir {
+            Some(dir) => layout(dir, calendar.year, day),
+            None => calendar.input_path(day),
+        }
+    }
+
+    /// The input for `day`, fetching and keeping it first if it is missing
+    /// and there is a fetcher to ask.
+    pub fn load(&self, calendar: &Calendar, day: u8) -> Result<String, InputError> {
+        let path = self.path(calendar, day);
+        let fetcher = match &self.fetcher {
+            Some(fetcher) if !path.exists() => fetcher,
+            _ => return load(path),
+        };
+        let input = fetcher
+            .fetch(calendar.year, day)
+            .map_err(|source| InputError::Fetch {
+                year: calendar.year,
+                day,
+                source,
+            })?;
+        if input.trim().is_empty() {
+            return Err(InputError::Empty(path));
+        }
+        let io = |source| InputError::Io {
+            path: path.clone(),
+            source,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io)?;
+        }
+        fs::write(&path, &input).map_err(io)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct Counting {
+        inner: DirFetcher,
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Fetcher for Counting {
+        fn fetch(&self, year: u16, day: u8) -> io::Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            self.inner.fetch(year, day)
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        let calendar = Calendar::new(2022, "2022", vec![]);
+        let cache = InputCache::new(None);
+        assert_eq!(
+            cache.path(&calendar, 7),
+            PathBuf::from("2022/input/007.txt")
+        );
+        let cache = InputCache::new(Some(PathBuf::from("inputs")));
+        assert_eq!(
+            cache.path(&calendar, 7),
+            PathBuf::from("inputs/2022/007.txt")
+        );
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let served = scratch("served");
+        fs::create_dir_all(served.join("2023")).unwrap();
+        fs::write(served.join("2023/004.txt"), "fetched\n").unwrap();
+        let cached = scratch("cached");
+        let calls = Rc::new(Cell::new(0));
+        let cache = InputCache::new(Some(cached.clone())).with_fetcher(Counting {
+            inner: DirFetcher::new(&served),
+            calls: calls.clone(),
+        });
+        let calendar = Calendar::new(2023, "2023", vec![]);
+
+        assert_eq!(cache.load(&calendar, 4).unwrap(), "fetched\n");
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            fs::read_to_string(cached.join("2023/004.txt")).unwrap(),
+            "fetched\n"
+        );
+        assert_eq!(cache.load(&calendar, 4).unwrap(), "fetched\n");
+        assert_eq!(calls.get(), 1);
+
+        match cache.load(&calendar, 5) {
+            Err(InputError::Fetch { year, day, .. }) => assert_eq!((year, day), (2023, 5)),
+            _ => panic!("expected a fetch error"),
+        }
+        assert!(!cached.join("2023/005.txt").exists());
+
+        fs::remove_dir_all(served).unwrap();
+        fs::remove_dir_all(cached).unwrap();
+    }
+
+    #[test]
+    fn test_no_fetcher() {
+        let cached = scratch("unfetched");
+        let cache = InputCache::new(Some(cached));
+        let calendar = Calendar::new(2023, "2023", vec![]);
+        assert!(matches!(
+            cache.load(&calendar, 4),
+            Err(InputError::Io { .. })
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{Image, ParseError, Screen, Solution};
+use std::path::PathBuf;
+
+/// A parsed day with its answer types erased, so days of different shapes can
+/// sit in the same list.
+pub trait Answers {
+    fn part1(&self) -> String;
+    fn part2(&self) -> String;
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool;
+    fn picture(&self) -> Option<Image>;
+}
+
+impl<S: Solution> Answers for S {
+    fn part1(&self) -> String {
+        Solution::part1(self).to_string()
+    }
+
+    fn part2(&self) -> String {
+        Solution::part2(self).to_string()
+    }
+
+    fn visualize(&self, part: u8, screen: &mut dyn Screen) -> bool {
+        Solution::visualize(self, part, screen)
+    }
+
+    fn picture(&self) -> Option<Image> {
+        Solution::picture(self)
+    }
+}
+
+fn parse_erased<S: Solution + 'static>(input: &str) -> Result<Box<dyn Answers>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// One registered solution, found by its day of the month.
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<Box<dyn Answers>, ParseError>,
+}
+
+impl Day {
+    pub fn new<S: Solution + 'static>(number: u8) -> Self {
+        Day {
+            number,
+            parse: parse_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Answers>, ParseError> {
+        (self.parse)(input)
+    }
+}
+
+/// Every solved day of one year, plus the directory that year's crate lives
+/// in, which is where its inputs and recorded answers are kept.
+pub struct Calendar {
+    pub year: u16,
+    pub root: PathBuf,
+    pub days: Vec<Day>,
+}
+
+impl Calendar {
+    pub fn new<P: Into<PathBuf>>(year: u16, root: P, days: Vec<Day>) -> Self {
+        Calendar {
+            year,
+            root: root.into(),
+            days,
+        }
+    }
+
+    pub fn day(&self, number: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.number == number)
+    }
+
+    /// The puzzle input for `number`, named the way the days have always
+    /// named them: `input/017.txt`.
+    pub fn input_path(&self, number: u8) -> PathBuf {
+        self.root.join("input").join(format!("{number:03}.txt"))
+    }
+
+    /// The accepted answers for the real inputs, see `Expected`.
+    pub fn answers_path(&self) -> PathBuf {
+        self.root.join("answers.toml")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unsolved;
+
+    struct Count(usize);
+
+    impl Solution for Count {
+        type Part1 = usize;
+        type Part2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            Ok(Count(input.lines().count()))
+        }
+        fn part1(&self) -> usize {
+            self.0
+        }
+        fn part2(&self) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_calendar() {
+        let calendar = Calendar::new(2022, "2022", vec![Day::new::<Count>(7)]);
+        assert!(calendar.day(6).is_none());
+        let answers = calendar.day(7).unwrap().parse("a\nb\nc").unwrap();
+        assert_eq!(answers.part1(), "3");
+        assert_eq!(answers.part2(), "unsolved");
+        assert_eq!(calendar.input_path(7), PathBuf::from("2022/input/007.txt"));
+        assert_eq!(calendar.answers_path(), PathBuf::from("2022/answers.toml"));
+    }
+}
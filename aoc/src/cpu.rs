@@ -0,0 +1,521 @@
+//! A cycle-accurate machine that runs assembled programs. Each day declares
+//! its instruction set as an `Isa`: the registers and their starting
+//! values, and for every instruction how many operands it takes, how many
+//! cycles it keeps the machine busy and what it does once it's done.
+//! Anything that watches the machine as it goes, like a signal sampler or a
+//! screen, is a `Peripheral` that sees the registers during every cycle.
+//! Breakpoints stop a `run` early, for the `Debugger`.
+
+use crate::ParseError;
+use std::fmt;
+
+/// What a register holds.
+pub type Word = i64;
+
+/// Something an instruction works on: a register, by its place in the
+/// `Isa`'s list of them, or a number written into the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Register(usize),
+    Value(Word),
+}
+
+/// Where the program goes once an instruction is done: on to the next, or
+/// some number of instructions forwards or back from this one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    Jump(isize),
+}
+
+/// What an instruction does when its last cycle ends.
+pub type Effect = fn(&mut Registers, &[Operand]) -> Flow;
+
+/// One row of an instruction table.
+#[derive(Debug)]
+pub struct Spec {
+    pub name: &'static str,
+    pub operands: usize,
+    pub cycles: usize,
+    pub effect: Effect,
+}
+
+/// An instruction set: the registers with the values they start with, and
+/// the instructions, looked up by name when a program is assembled.
+#[derive(Debug)]
+pub struct Isa {
+    pub registers: &'static [(&'static str, Word)],
+    pub instructions: &'static [Spec],
+}
+
+impl Isa {
+    pub fn register(&self, name: &str) -> Option<usize> {
+        self.registers.iter().position(|&(r, _)| r == name)
+    }
+
+    /// Reads one line of a program, like `addx -5` or `jnz a 2`.
+    pub fn instruction(&'static self, line: &str) -> Result<Instruction, ParseError> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let opcode = self
+            .instructions
+            .iter()
+            .position(|spec| spec.name == name)
+            .ok_or_else(|| ParseError::new("unrecognized operator", line, name))?;
+        let spec = &self.instructions[opcode];
+        let operands = words
+            .map(|word| match self.register(word) {
+                Some(r) => Ok(Operand::Register(r)),
+                None => word
+                    .parse()
+                    .map(Operand::Value)
+                    .map_err(|_| ParseError::new("neither a number nor a register", line, word)),
+            })
+            .collect::<Result<Vec<Operand>, ParseError>>()?;
+        if operands.len() < spec.operands {
+            return Err(ParseError::line("missing operand", line));
+        }
+        if operands.len() > spec.operands {
+            let extra = line.split_whitespace().nth(spec.operands + 1).unwrap();
+            return Err(ParseError::new("unexpected operand", line, extra));
+        }
+        Ok(Instruction {
+            isa: self,
+            opcode,
+            operands,
+        })
+    }
+
+    /// Reads a program, an instruction a line.
+    pub fn assemble(&'static self, input: &str) -> Result<Vec<Instruction>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| self.instruction(line).map_err(|e| e.below(i)))
+            .collect()
+    }
+}
+
+/// An instruction of a program, with its operands.
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    isa: &'static Isa,
+    opcode: usize,
+    operands: Vec<Operand>,
+}
+
+impl Instruction {
+    pub fn spec(&self) -> &'static Spec {
+        &self.isa.instructions[self.opcode]
+    }
+
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands
+    }
+}
+
+impl PartialEq for Instruction {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.isa, other.isa)
+            && self.opcode == other.opcode
+            && self.operands == other.operands
+    }
+}
+
+impl Eq for Instruction {}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.spec().name)?;
+        for operand in &self.operands {
+            match operand {
+                Operand::Register(r) => write!(f, " {}", self.isa.registers[*r].0)?,
+                Operand::Value(v) => write!(f, " {v}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The registers of a machine, laid out as its `Isa` says.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Registers {
+    names: Vec<&'static str>,
+    values: Vec<Word>,
+}
+
+impl Registers {
+    fn new(isa: &Isa) -> Self {
+        Registers {
+            names: isa.registers.iter().map(|&(name, _)| name).collect(),
+            values: isa.registers.iter().map(|&(_, value)| value).collect(),
+        }
+    }
+
+    pub fn get(&self, r: usize) -> Word {
+        self.values[r]
+    }
+
+    pub fn set(&mut self, r: usize, value: Word) {
+        self.values[r] = value;
+    }
+
+    pub fn named(&self, name: &str) -> Option<Word> {
+        let r = self.names.iter().position(|&n| n == name)?;
+        Some(self.values[r])
+    }
+
+    /// What an operand stands for right now.
+    pub fn value(&self, operand: Operand) -> Word {
+        match operand {
+            Operand::Register(r) => self.values[r],
+            Operand::Value(v) => v,
+        }
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (name, value)) in self.names.iter().zip(&self.values).enumerate() {
+            let gap = if i == 0 { "" } else { " " };
+            write!(f, "{gap}{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Something wired up to a machine, that sees it as it runs.
+pub trait Peripheral {
+    /// Sees the registers during `cycle`, counting from 1, before anything
+    /// finishing in that cycle has changed them.
+    fn tick(&mut self, cycle: usize, registers: &Registers);
+
+    /// What it has to show so far, for the debugger.
+    fn report(&self) -> String {
+        String::new()
+    }
+}
+
+/// Nothing wired up at all.
+impl Peripheral for () {
+    fn tick(&mut self, _cycle: usize, _registers: &Registers) {}
+}
+
+impl<P: Peripheral + ?Sized> Peripheral for &mut P {
+    fn tick(&mut self, cycle: usize, registers: &Registers) {
+        (**self).tick(cycle, registers)
+    }
+
+    fn report(&self) -> String {
+        (**self).report()
+    }
+}
+
+impl<P: Peripheral + ?Sized> Peripheral for Box<P> {
+    fn tick(&mut self, cycle: usize, registers: &Registers) {
+        (**self).tick(cycle, registers)
+    }
+
+    fn report(&self) -> String {
+        (**self).report()
+    }
+}
+
+/// Two peripherals on the same machine, each seeing every cycle.
+impl<A: Peripheral, B: Peripheral> Peripheral for (A, B) {
+    fn tick(&mut self, cycle: usize, registers: &Registers) {
+        self.0.tick(cycle, registers);
+        self.1.tick(cycle, registers);
+    }
+
+    fn report(&self) -> String {
+        self.0.report() + &self.1.report()
+    }
+}
+
+/// Where a `run` stops: before the given cycle starts, or before the
+/// instruction at the given address starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    Cycle(usize),
+    Address(usize),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Cycle(c) => write!(f, "cycle {c}"),
+            Breakpoint::Address(a) => write!(f, "pc {a}"),
+        }
+    }
+}
+
+/// How a machine is getting on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    /// The program counter has left the program.
+    Halted,
+    Break(Breakpoint),
+}
+
+/// A program loaded into a machine, run a cycle at a time.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    program: Vec<Instruction>,
+    registers: Registers,
+    /// The address of the instruction being worked on, which may be
+    /// anywhere once a jump has left the program.
+    pc: isize,
+    /// How many cycles have finished.
+    cycle: usize,
+    /// How many cycles the current instruction has had so far.
+    busy: usize,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl Machine {
+    pub fn new(isa: &'static Isa, program: Vec<Instruction>) -> Self {
+        Machine {
+            program,
+            registers: Registers::new(isa),
+            pc: 0,
+            cycle: 0,
+            busy: 0,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn pc(&self) -> isize {
+        self.pc
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// The instruction being worked on, unless the program has finished.
+    pub fn current(&self) -> Option<&Instruction> {
+        usize::try_from(self.pc)
+            .ok()
+            .and_then(|pc| self.program.get(pc))
+    }
+
+    /// How many cycles the current instruction has had so far.
+    pub fn busy(&self) -> usize {
+        self.busy
+    }
+
+    pub fn halted(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Runs one cycle, with `bus` seeing the registers during it, and
+    /// finishes the current instruction if this was its last cycle.
+    pub fn step<P: Peripheral + ?Sized>(&mut self, bus: &mut P) -> Status {
+        let Some(spec) = self.current().map(Instruction::spec) else {
+            return Status::Halted;
+        };
+        self.cycle += 1;
+        bus.tick(self.cycle, &self.registers);
+        self.busy += 1;
+        if self.busy >= spec.cycles {
+            let operands = &self.program[self.pc as usize].operands;
+            let flow = (spec.effect)(&mut self.registers, operands);
+            self.pc += match flow {
+                Flow::Next => 1,
+                Flow::Jump(offset) => offset,
+            };
+            self.busy = 0;
+        }
+        match self.halted() {
+            true => Status::Halted,
+            false => Status::Running,
+        }
+    }
+
+    /// Runs until the program finishes or a breakpoint is reached, always
+    /// moving on at least one cycle so a run can carry on from a breakpoint.
+    pub fn run<P: Peripheral + ?Sized>(&mut self, bus: &mut P) -> Status {
+        loop {
+            if self.step(bus) == Status::Halted {
+                return Status::Halted;
+            }
+            if let Some(b) = self.at_breakpoint() {
+                return Status::Break(b);
+            }
+        }
+    }
+
+    /// Runs until the current instruction is done, and says how things
+    /// stand after.
+    pub fn finish<P: Peripheral + ?Sized>(&mut self, bus: &mut P) -> Status {
+        loop {
+            let status = self.step(bus);
+            if status == Status::Halted || self.busy == 0 {
+                return status;
+            }
+        }
+    }
+
+    /// The first breakpoint that says to stop here, if any.
+    pub fn at_breakpoint(&self) -> Option<Breakpoint> {
+        self.breakpoints.iter().copied().find(|b| match *b {
+            Breakpoint::Cycle(c) => self.cycle + 1 == c,
+            Breakpoint::Address(a) => self.busy == 0 && self.pc == a as isize,
+        })
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    /// Says whether there was such a breakpoint to remove.
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let before = self.breakpoints.len();
+        self.breakpoints.retain(|&b| b != breakpoint);
+        self.breakpoints.len() < before
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const A: usize = 0;
+    const B: usize = 1;
+
+    /// A little instruction set with two registers and a loop.
+    pub(crate) static TOY: Isa = Isa {
+        registers: &[("a", 0), ("b", 10)],
+        instructions: &[
+            Spec {
+                name: "set",
+                operands: 2,
+                cycles: 1,
+                effect: |r, ops| {
+                    if let Operand::Register(x) = ops[0] {
+                        r.set(x, r.value(ops[1]));
+                    }
+                    Flow::Next
+                },
+            },
+            Spec {
+                name: "dec",
+                operands: 1,
+                cycles: 2,
+                effect: |r, ops| {
+                    if let Operand::Register(x) = ops[0] {
+                        r.set(x, r.get(x) - 1);
+                    }
+                    Flow::Next
+                },
+            },
+            Spec {
+                name: "jnz",
+                operands: 2,
+                cycles: 3,
+                effect: |r, ops| match r.value(ops[0]) {
+                    0 => Flow::Next,
+                    _ => Flow::Jump(r.value(ops[1]) as isize),
+                },
+            },
+        ],
+    };
+
+    pub(crate) const COUNTDOWN: &str = "set a 2\ndec a\njnz a -1\nset b a";
+
+    /// Writes down every cycle it sees, with what was in `a`.
+    #[derive(Default)]
+    struct Probe(Vec<(usize, Word)>);
+
+    impl Peripheral for Probe {
+        fn tick(&mut self, cycle: usize, registers: &Registers) {
+            self.0.push((cycle, registers.get(A)));
+        }
+    }
+
+    #[test]
+    fn test_assemble() {
+        let program = TOY.assemble(COUNTDOWN).unwrap();
+        assert_eq!(program.len(), 4);
+        assert_eq!(
+            program[0].operands(),
+            [Operand::Register(A), Operand::Value(2)]
+        );
+        assert_eq!(program[2].spec().cycles, 3);
+        assert_eq!(program[2].to_string(), "jnz a -1");
+        assert_eq!(program[1], TOY.instruction("dec  a").unwrap());
+
+        let e = TOY.assemble("set a 1\nset c 1").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "c"));
+        let e = TOY.assemble("mul a 2").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.reason.as_str()),
+            (1, 1, "unrecognized operator")
+        );
+        let e = TOY.instruction("dec a b").unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (7, "unexpected operand"));
+        assert_eq!(
+            TOY.instruction("set a").unwrap_err().reason,
+            "missing operand"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut machine = Machine::new(&TOY, TOY.assemble(COUNTDOWN).unwrap());
+        let mut probe = Probe::default();
+        assert_eq!(machine.run(&mut probe), Status::Halted);
+        // set, dec, jnz back, dec, jnz on, set
+        assert_eq!(machine.cycle(), 1 + 2 + 3 + 2 + 3 + 1);
+        assert_eq!(probe.0.len(), 12);
+        // a only changes once the instruction changing it is done
+        assert_eq!(probe.0[..4], [(1, 0), (2, 2), (3, 2), (4, 1)]);
+        assert_eq!(machine.registers().to_string(), "a=0 b=0");
+        assert_eq!(machine.registers().named("b"), Some(0));
+        assert_eq!(machine.step(&mut ()), Status::Halted);
+        assert_eq!(machine.cycle(), 12);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut machine = Machine::new(&TOY, TOY.assemble(COUNTDOWN).unwrap());
+        machine.add_breakpoint(Breakpoint::Address(1));
+        machine.add_breakpoint(Breakpoint::Cycle(11));
+        assert_eq!(machine.run(&mut ()), Status::Break(Breakpoint::Address(1)));
+        assert_eq!((machine.cycle(), machine.registers().get(A)), (1, 2));
+        // the loop comes back round to the same place
+        assert_eq!(machine.run(&mut ()), Status::Break(Breakpoint::Address(1)));
+        assert_eq!((machine.cycle(), machine.registers().get(A)), (6, 1));
+        assert_eq!(machine.run(&mut ()), Status::Break(Breakpoint::Cycle(11)));
+        assert_eq!(machine.current().unwrap().to_string(), "jnz a -1");
+        assert_eq!(machine.busy(), 2);
+        assert!(machine.remove_breakpoint(Breakpoint::Cycle(11)));
+        assert!(!machine.remove_breakpoint(Breakpoint::Cycle(11)));
+        assert_eq!(machine.run(&mut ()), Status::Halted);
+        assert_eq!(machine.registers().get(B), 0);
+    }
+
+    #[test]
+    fn test_finish() {
+        let mut machine = Machine::new(&TOY, TOY.assemble(COUNTDOWN).unwrap());
+        assert_eq!(machine.finish(&mut ()), Status::Running);
+        assert_eq!(machine.finish(&mut ()), Status::Running);
+        assert_eq!((machine.cycle(), machine.pc()), (3, 2));
+    }
+}
@@ -0,0 +1,256 @@
+//! A step debugger over a `Machine` and whatever is wired up to it, driven
+//! a command line at a time so it can be typed at or fed a script: stepping
+//! by cycle or by instruction, running to breakpoints, and looking at the
+//! registers, the program and what the peripherals have to show.
+
+use crate::cpu::{Breakpoint, Machine, Peripheral, Status};
+
+const HELP: &str = "\
+step [N]                    run N cycles (s)
+next [N]                    run N whole instructions (n)
+continue                    run to a breakpoint or the end (c)
+break cycle N | pc N        stop before cycle N, or before the instruction at N (b)
+delete [cycle N | pc N]     remove one breakpoint, or all of them (d)
+info                        list the breakpoints (i)
+regs                        show the registers (r)
+list                        show the program around where it's got to (l)
+peek                        show what the peripherals have (p)
+";
+
+/// How many instructions `list` shows either side of the current one.
+const CONTEXT: usize = 4;
+
+pub struct Debugger<P> {
+    machine: Machine,
+    bus: P,
+}
+
+fn count(name: &str, args: &[&str]) -> Result<usize, String> {
+    match args {
+        [] => Ok(1),
+        [n] => n
+            .parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| format!("{name}: {n} isn't a count")),
+        _ => Err(format!("{name}: too many arguments")),
+    }
+}
+
+fn breakpoint(name: &str, args: &[&str]) -> Result<Breakpoint, String> {
+    let number = |n: &str| n.parse().map_err(|_| format!("{name}: {n} isn't a number"));
+    match args {
+        ["cycle", n] => Ok(Breakpoint::Cycle(number(n)?)),
+        ["pc", n] => Ok(Breakpoint::Address(number(n)?)),
+        _ => Err(format!("usage: {name} cycle N | pc N")),
+    }
+}
+
+impl<P: Peripheral> Debugger<P> {
+    pub fn new(machine: Machine, bus: P) -> Self {
+        Debugger { machine, bus }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn bus(&self) -> &P {
+        &self.bus
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("(cycle {}) ", self.machine.cycle())
+    }
+
+    /// Where the machine has got to: what the registers will hold during the
+    /// next cycle, and how far into which instruction it is.
+    pub fn position(&self) -> String {
+        let m = &self.machine;
+        match m.current() {
+            Some(instruction) => format!(
+                "before cycle {}: {}, pc {}: {instruction} [{}/{}]\n",
+                m.cycle() + 1,
+                m.registers(),
+                m.pc(),
+                m.busy(),
+                instruction.spec().cycles
+            ),
+            None => format!("halted after cycle {}: {}\n", m.cycle(), m.registers()),
+        }
+    }
+
+    /// Runs one command line, and says what it printed or what was wrong.
+    pub fn run(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            return Err(String::from("nothing to run"));
+        };
+        match (name, args) {
+            ("step" | "s", args) => {
+                for _ in 0..count(name, args)? {
+                    self.machine.step(&mut self.bus);
+                }
+                Ok(self.position())
+            }
+            ("next" | "n", args) => {
+                for _ in 0..count(name, args)? {
+                    self.machine.finish(&mut self.bus);
+                }
+                Ok(self.position())
+            }
+            ("continue" | "c", []) => match self.machine.run(&mut self.bus) {
+                Status::Break(b) => Ok(format!("stopped at {b}\n{}", self.position())),
+                _ => Ok(self.position()),
+            },
+            ("break" | "b", args) => {
+                let b = breakpoint(name, args)?;
+                self.machine.add_breakpoint(b);
+                Ok(format!("breakpoint at {b}\n"))
+            }
+            ("delete" | "d", []) => {
+                for b in self.machine.breakpoints().to_vec() {
+                    self.machine.remove_breakpoint(b);
+                }
+                Ok(String::new())
+            }
+            ("delete" | "d", args) => {
+                let b = breakpoint(name, args)?;
+                match self.machine.remove_breakpoint(b) {
+                    true => Ok(String::new()),
+                    false => Err(format!("{name}: no breakpoint at {b}")),
+                }
+            }
+            ("info" | "i", []) => Ok(self
+                .machine
+                .breakpoints()
+                .iter()
+                .map(|b| format!("breakpoint at {b}\n"))
+                .collect()),
+            ("regs" | "r", []) => Ok(format!("{}\n", self.machine.registers())),
+            ("list" | "l", []) => Ok(self.listing()),
+            ("peek" | "p", []) => Ok(self.bus.report()),
+            ("help" | "h", []) => Ok(String::from(HELP)),
+            (
+                "continue" | "c" | "info" | "i" | "regs" | "r" | "list" | "l" | "peek" | "p"
+                | "help" | "h",
+                _,
+            ) => Err(format!("{name}: too many arguments")),
+            _ => Err(format!("{name}: unknown command, try help")),
+        }
+    }
+
+    /// The program around the current instruction, which is marked.
+    fn listing(&self) -> String {
+        let program = self.machine.program();
+        let pc = self.machine.pc();
+        let here = pc.clamp(0, program.len() as isize) as usize;
+        let from = here.saturating_sub(CONTEXT);
+        let to = (here + CONTEXT + 1).min(program.len());
+        (from..to)
+            .map(|i| {
+                let mark = if i as isize == pc { "=>" } else { "  " };
+                format!("{mark} {i:>4}  {}\n", program[i])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::tests::{COUNTDOWN, TOY};
+    use crate::cpu::Registers;
+
+    /// Counts the cycles it has seen.
+    struct Clock(usize);
+
+    impl Peripheral for Clock {
+        fn tick(&mut self, _cycle: usize, _registers: &Registers) {
+            self.0 += 1;
+        }
+
+        fn report(&self) -> String {
+            format!("ticks: {}\n", self.0)
+        }
+    }
+
+    fn debugger() -> Debugger<Clock> {
+        Debugger::new(
+            Machine::new(&TOY, TOY.assemble(COUNTDOWN).unwrap()),
+            Clock(0),
+        )
+    }
+
+    #[test]
+    fn test_stepping() {
+        let mut debugger = debugger();
+        assert_eq!(debugger.prompt(), "(cycle 0) ");
+        assert_eq!(
+            debugger.run("step").unwrap(),
+            "before cycle 2: a=2 b=10, pc 1: dec a [0/2]\n"
+        );
+        assert_eq!(
+            debugger.run("s").unwrap(),
+            "before cycle 3: a=2 b=10, pc 1: dec a [1/2]\n"
+        );
+        assert_eq!(
+            debugger.run("next 2").unwrap(),
+            "before cycle 7: a=1 b=10, pc 1: dec a [0/2]\n"
+        );
+        assert_eq!(debugger.run("peek").unwrap(), "ticks: 6\n");
+        assert_eq!(debugger.run("regs").unwrap(), "a=1 b=10\n");
+        assert_eq!(
+            debugger.run("list").unwrap(),
+            "      0  set a 2\n=>    1  dec a\n      2  jnz a -1\n      3  set b a\n"
+        );
+        assert_eq!(
+            debugger.run("step 100").unwrap(),
+            "halted after cycle 12: a=0 b=0\n"
+        );
+        assert_eq!(debugger.bus().0, 12);
+    }
+
+    #[test]
+    fn test_breaking() {
+        let mut debugger = debugger();
+        assert_eq!(debugger.run("b pc 3").unwrap(), "breakpoint at pc 3\n");
+        debugger.run("break cycle 5").unwrap();
+        assert_eq!(
+            debugger.run("info").unwrap(),
+            "breakpoint at pc 3\nbreakpoint at cycle 5\n"
+        );
+        assert_eq!(
+            debugger.run("c").unwrap(),
+            "stopped at cycle 5\nbefore cycle 5: a=1 b=10, pc 2: jnz a -1 [1/3]\n"
+        );
+        assert_eq!(
+            debugger.run("continue").unwrap(),
+            "stopped at pc 3\nbefore cycle 12: a=0 b=10, pc 3: set b a [0/1]\n"
+        );
+        debugger.run("delete").unwrap();
+        assert_eq!(debugger.run("info").unwrap(), "");
+        assert_eq!(
+            debugger.run("c").unwrap(),
+            "halted after cycle 12: a=0 b=0\n"
+        );
+        assert!(debugger.run("d pc 3").is_err());
+    }
+
+    #[test]
+    fn test_mistakes() {
+        let mut debugger = debugger();
+        assert!(debugger.run("").is_err());
+        assert!(debugger.run("step 0").is_err());
+        assert!(debugger.run("step many").is_err());
+        assert!(debugger.run("break 3").is_err());
+        assert!(debugger.run("break pc x").is_err());
+        assert!(debugger.run("regs a").is_err());
+        assert_eq!(
+            debugger.run("jump").unwrap_err(),
+            "jump: unknown command, try help"
+        );
+        assert!(debugger.run("help").unwrap().contains("continue"));
+        assert_eq!(debugger.machine().cycle(), 0);
+    }
+}
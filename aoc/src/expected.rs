@@ -0,0 +1,164 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{error, fmt, fs, io};
+
+#[derive(Debug)]
+pub enum ExpectedError {
+    Io { path: PathBuf, source: io::Error },
+    Toml(toml::de::Error),
+    Day(String),
+}
+
+impl fmt::Display for ExpectedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExpectedError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            ExpectedError::Toml(e) => write!(f, "{e}"),
+            ExpectedError::Day(key) => write!(f, "[{key}] is not a day of the month"),
+        }
+    }
+}
+
+impl error::Error for ExpectedError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ExpectedError::Io { source, .. } => Some(source),
+            ExpectedError::Toml(e) => Some(e),
+            ExpectedError::Day(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Record {
+    pub(crate) part1: Option<String>,
+    pub(crate) part2: Option<String>,
+}
+
+/// The accepted answers for a year's real inputs, kept in `answers.toml`:
+///
+/// ```toml
+/// [017]
+/// part1 = "3071"
+/// part2 = "1523615160362"
+/// ```
+///
+/// A part that was never accepted is simply left out.
+#[derive(Debug, Default)]
+pub struct Expected {
+    days: BTreeMap<u8, Record>,
+}
+
+impl Expected {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ExpectedError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ExpectedError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        text.parse()
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let record = self.days.get(&day)?;
+        match part {
+            1 => record.part1.as_deref(),
+            _ => record.part2.as_deref(),
+        }
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+impl std::str::FromStr for Expected {
+    type Err = ExpectedError;
+
+    fn from_str(text: &str) -> Result<Self, ExpectedError> {
+        let raw: BTreeMap<String, Record> = toml::from_str(text).map_err(ExpectedError::Toml)?;
+        let mut days = BTreeMap::new();
+        for (key, record) in raw {
+            let day = match key.parse::<u8>() {
+                Ok(day @ 1..=25) => day,
+                _ => return Err(ExpectedError::Day(key)),
+            };
+            days.insert(day, record);
+        }
+        Ok(Expected { days })
+    }
+}
+
+/// How one part's answer compares to the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unrecorded,
+}
+
+impl Verdict {
+    /// Surrounding whitespace is ignored so that multi-line answers like a
+    /// CRT screen can be written naturally in the toml.
+    pub fn of(expected: Option<&str>, got: &str) -> Self {
+        match expected {
+            None => Verdict::Unrecorded,
+            Some(e) if e.trim() == got.trim() => Verdict::Pass,
+            Some(e) => Verdict::Fail {
+                expected: e.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"
+[001]
+part1 = "24000"
+part2 = "45000"
+
+[010]
+part2 = '''
+##..##..
+###...##
+'''
+"#;
+
+    #[test]
+    fn test_parse() {
+        let expected: Expected = SAMPLE.parse().unwrap();
+        assert_eq!(expected.days().collect::<Vec<_>>(), vec![1, 10]);
+        assert_eq!(expected.get(1, 1), Some("24000"));
+        assert_eq!(expected.get(1, 2), Some("45000"));
+        assert_eq!(expected.get(10, 1), None);
+        assert_eq!(expected.get(2, 1), None);
+        assert!("[026]\npart1 = \"1\"".parse::<Expected>().is_err());
+        assert!("[001]\npart3 = \"1\"".parse::<Expected>().is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let expected: Expected = SAMPLE.parse().unwrap();
+        assert_eq!(Verdict::of(expected.get(1, 1), "24000"), Verdict::Pass);
+        assert_eq!(
+            Verdict::of(expected.get(1, 2), "44999"),
+            Verdict::Fail {
+                expected: "45000".to_string()
+            }
+        );
+        assert_eq!(
+            Verdict::of(expected.get(10, 1), "13140"),
+            Verdict::Unrecorded
+        );
+        assert_eq!(
+            Verdict::of(expected.get(10, 2), "\n##..##..\n###...##"),
+            Verdict::Pass
+        );
+    }
+}
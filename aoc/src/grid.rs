@@ -0,0 +1,340 @@
+use crate::parse::ParseError;
+use crate::point::{Direction, Direction8, Point2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell of a grid as `(x, y)`: column first, row second, origin top left.
+pub type Pos = (usize, usize);
+
+/// A rectangular map of cells stored row by row, the shape most puzzles hand
+/// out as lines of characters.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Rows become columns: the cell at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// A quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// A quarter turn anticlockwise, so the right column becomes the top row.
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from one line of text per row, mapping every character
+    /// through `cell`. Every line has to be as wide as the first.
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut cell: F) -> Self {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    /// Like `parse`, but for puzzle input: `cell` gives `None` for a
+    /// character it doesn't recognise, and that or a line that isn't as wide
+    /// as the first is a `ParseError` blaming it, rather than a panic.
+    pub fn try_parse<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (at, c) in line.char_indices() {
+                match cell(c) {
+                    Some(t) => cells.push(t),
+                    None => {
+                        let bad = &line[at..at + c.len_utf8()];
+                        let e = ParseError::new("unrecognized character", line, bad);
+                        return Err(e.below(i));
+                    }
+                }
+            }
+            let row = cells.len() - start;
+            match width {
+                Some(width) if width != row => {
+                    let reason = format!("row is {row} wide, not {width}");
+                    return Err(ParseError::line(reason, line).below(i));
+                }
+                _ => width = Some(row),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows are not all {width} wide"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset<I: TryInto<usize>>(&self, x: I, y: I) -> Option<usize> {
+        let x = x.try_into().ok()?;
+        let y = y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// The cell at `(x, y)`, or `None` when that is off the grid, including
+    /// when a signed coordinate is negative.
+    pub fn get<I: TryInto<usize>>(&self, x: I, y: I) -> Option<&T> {
+        self.offset(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, x: I, y: I) -> Option<&mut T> {
+        self.offset(x, y).map(|i| &mut self.cells[i])
+    }
+
+    fn step(&self, (x, y): Pos, d: Point2<isize>) -> Option<Pos> {
+        let x = x.checked_add_signed(d.x)?;
+        let y = y.checked_add_signed(d.y)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// The up to four cells sharing an edge with `pos`, clockwise from north.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.offset()))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`,
+    /// clockwise from north.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.offset()))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is off the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell alongside its position, row by row.
+    pub fn indexed(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, whose cell satisfies `pred`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<Pos> {
+        self.indexed().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is off the grid"))
+    }
+}
+
+/// Renders the grid back to text, one line per row and no trailing newline,
+/// so a `Grid<char>` prints the way its input looked.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"abc
+def"#;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(SAMPLE, |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.to_string(), SAMPLE);
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.iter().sum::<u32>(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "not all 3 wide")]
+    fn test_parse_ragged() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert_eq!(
+            Grid::try_parse(SAMPLE, Some),
+            Ok(Grid::parse(SAMPLE, |c| c))
+        );
+        let e = Grid::try_parse("abc\ndef\nhi\njk", Some).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "hi"));
+        assert_eq!(e.reason, "row is 2 wide, not 3");
+        let e = Grid::try_parse("12\n3é\n56", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "é"));
+        assert_eq!(e.reason, "unrecognized character");
+        let empty = Grid::try_parse("", Some).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::parse(SAMPLE, |c| c);
+        assert_eq!(grid.get(1, 1), Some(&'e'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1i32, 0), None);
+        *grid.get_mut(1usize, 0).unwrap() = 'B';
+        grid[(2, 1)] = 'F';
+        assert_eq!(grid.to_string(), "aBc\ndeF");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(SAMPLE, |c| c);
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.indexed().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse(SAMPLE, |c| c);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,239 @@
+//! Pictures of the days, for when a `Grid` is too big to read as text: a
+//! cell per pixel coloured by a palette, with paths drawn over the top, and
+//! 3D boards cut into slices laid side by side. Saved as PPM, or as PNG when
+//! built with the `png` feature.
+
+use crate::grid::{Grid, Pos};
+use crate::point::Point3;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// A colour, red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [128, 128, 128];
+pub const RED: Rgb = [220, 40, 40];
+pub const ORANGE: Rgb = [240, 150, 30];
+pub const BLUE: Rgb = [40, 90, 220];
+
+/// The colour `t` of the way from `from` to `to`, with `t` clamped to
+/// between 0 and 1.
+pub fn gradient(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    [
+        mix(from[0], to[0]),
+        mix(from[1], to[1]),
+        mix(from[2], to[2]),
+    ]
+}
+
+/// A picture, its pixels a row at a time from the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// A pixel for every cell of `grid`, in the colour `palette` gives it.
+    pub fn from_grid<T, F: FnMut(&T) -> Rgb>(grid: &Grid<T>, palette: F) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(palette).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Colours one pixel, if it's on the picture at all.
+    pub fn set(&mut self, (x, y): Pos, colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    /// Draws a path over the picture a place at a time, so the steps have to
+    /// be next to each other for it to come out as a line.
+    pub fn overlay<I: IntoIterator<Item = Pos>>(&mut self, path: I, colour: Rgb) {
+        for p in path {
+            self.set(p, colour);
+        }
+    }
+
+    /// The picture with every pixel blown up into a `factor` by `factor`
+    /// square, since one pixel a cell is too small to see.
+    pub fn scale(&self, factor: usize) -> Image {
+        let mut scaled = Image::new(self.width * factor, self.height * factor, BLACK);
+        for (i, pixel) in scaled.pixels.iter_mut().enumerate() {
+            let (x, y) = (i % scaled.width / factor, i / scaled.width / factor);
+            *pixel = self.pixels[y * self.width + x];
+        }
+        scaled
+    }
+
+    /// Writes the picture as a binary PPM.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())?;
+        out.flush()
+    }
+
+    /// Writes the picture as a PNG.
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels.concat())
+            .map_err(io::Error::other)
+    }
+
+    /// Saves the picture as a PNG if `path` ends in `.png`, and otherwise as
+    /// a PPM.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let png = path.extension().is_some_and(|e| e == "png");
+        if png && cfg!(not(feature = "png")) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "saving a PNG needs the png feature, try .ppm instead",
+            ));
+        }
+        let out = BufWriter::new(File::create(path)?);
+        #[cfg(feature = "png")]
+        if png {
+            return self.write_png(out);
+        }
+        self.write_ppm(out)
+    }
+}
+
+/// A 3D board from `lb` to `ub` inclusive, cut into a slice for every `z`
+/// and laid out left to right with a grey line between each, with the
+/// colour of each place given by `voxel`. Bounds with nothing between
+/// them, like those of an empty board, make an empty image.
+pub fn slices<F: FnMut(Point3<i32>) -> Rgb>(
+    lb: Point3<i32>,
+    ub: Point3<i32>,
+    mut voxel: F,
+) -> Image {
+    if ub.x < lb.x || ub.y < lb.y || ub.z < lb.z {
+        return Image::new(0, 0, GREY);
+    }
+    let (w, h) = ((ub.x - lb.x + 1) as usize, (ub.y - lb.y + 1) as usize);
+    let n = (ub.z - lb.z + 1) as usize;
+    let mut sheet = Image::new(n * (w + 1) - 1, h, GREY);
+    for (k, z) in (lb.z..=ub.z).enumerate() {
+        for (j, y) in (lb.y..=ub.y).enumerate() {
+            for (i, x) in (lb.x..=ub.x).enumerate() {
+                sheet.set((k * (w + 1) + i, j), voxel(Point3::new(x, y, z)));
+            }
+        }
+    }
+    sheet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(gradient(BLACK, WHITE, 0.0), BLACK);
+        assert_eq!(gradient(BLACK, WHITE, 0.5), [128, 128, 128]);
+        assert_eq!(gradient(BLACK, [200, 100, 0], 2.0), [200, 100, 0]);
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("#.\n.#\n..", |c| c == '#');
+        let mut image = Image::from_grid(&grid, |&rock| if rock { BLACK } else { WHITE });
+        assert_eq!((image.width(), image.height()), (2, 3));
+        assert_eq!(image.get((1, 1)), Some(BLACK));
+        assert_eq!(image.get((1, 2)), Some(WHITE));
+        assert_eq!(image.get((2, 0)), None);
+        image.overlay([(0, 1), (0, 2), (1, 2), (5, 5)], RED);
+        assert_eq!(image.get((0, 2)), Some(RED));
+        assert_eq!(image.get((0, 0)), Some(BLACK));
+
+        let big = image.scale(3);
+        assert_eq!((big.width(), big.height()), (6, 9));
+        assert_eq!(big.get((5, 5)), Some(BLACK));
+        assert_eq!(big.get((2, 8)), Some(RED));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut image = Image::new(2, 1, WHITE);
+        image.set((1, 0), RED);
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\xff\xff\xdc\x28\x28");
+    }
+
+    #[test]
+    fn test_slices() {
+        let lb = Point3::new(0, 0, 1);
+        let ub = Point3::new(1, 2, 3);
+        let sheet = slices(lb, ub, |p| if p.x == p.z % 2 { RED } else { WHITE });
+        // three slices two wide, with a line between each
+        assert_eq!((sheet.width(), sheet.height()), (8, 3));
+        assert_eq!(sheet.get((0, 0)), Some(WHITE));
+        assert_eq!(sheet.get((1, 0)), Some(RED));
+        assert_eq!(sheet.get((2, 1)), Some(GREY));
+        assert_eq!(sheet.get((3, 2)), Some(RED));
+        assert_eq!(sheet.get((7, 0)), Some(RED));
+
+        let empty = slices(Point3::new(0, 0, 0), Point3::new(-1, -1, -1), |_| RED);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        let flat = slices(lb, Point3::new(1, -1, 3), |_| RED);
+        assert_eq!((flat.width(), flat.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let image = Image::new(3, 2, BLUE);
+        image.save(dir.join("blue.ppm")).unwrap();
+        let ppm = std::fs::read(dir.join("blue.ppm")).unwrap();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 3 * 2 * 3);
+        let png = image.save(dir.join("blue.png"));
+        #[cfg(feature = "png")]
+        {
+            png.unwrap();
+            let png = std::fs::read(dir.join("blue.png")).unwrap();
+            assert!(png.starts_with(b"\x89PNG"));
+        }
+        #[cfg(not(feature = "png"))]
+        assert_eq!(png.unwrap_err().kind(), io::ErrorKind::Unsupported);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+use std::{error, fmt, fs, io};
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Empty(PathBuf),
+    Fetch {
+        year: u16,
+        day: u8,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            InputError::Empty(path) => write!(f, "{} is empty", path.display()),
+            InputError::Fetch { year, day, source } => {
+                write!(f, "could not fetch {year} day {day:02}: {source}")
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } | InputError::Fetch { source, .. } => Some(source),
+            InputError::Empty(_) => None,
+        }
+    }
+}
+
+/// Reads a whole puzzle input, refusing empty files since those are always a
+/// forgotten paste rather than a real puzzle.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let input = fs::read_to_string(path).map_err(|source| InputError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(input)
+}
+
+/// Owned lines, the same thing `BufReader::lines()` used to hand out.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// Groups of lines separated by blank lines.
+pub fn paragraphs(input: &str) -> Vec<Vec<&str>> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = r#"1000
+2000
+
+3000
+
+4000
+5000
+"#;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines(SAMPLE).len(), 7);
+        assert_eq!(lines(SAMPLE)[2], "");
+        assert_eq!(lines("a\r\nb"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let p = paragraphs(SAMPLE);
+        assert_eq!(p.len(), 3);
+        assert_eq!(p[0], vec!["1000", "2000"]);
+        assert_eq!(p[1], vec!["3000"]);
+        assert_eq!(p[2], vec!["4000", "5000"]);
+        assert!(paragraphs("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_load_missing() {
+        match load("no/such/input.txt") {
+            Err(InputError::Io { path, .. }) => {
+                assert_eq!(path.to_str(), Some("no/such/input.txt"))
+            }
+            _ => panic!("expected an io error"),
+        }
+    }
+
+    #[test]
+    fn test_load_empty() {
+        let path = std::env::temp_dir().join("aoc-empty-input.txt");
+        std::fs::write(&path, "\n").unwrap();
+        assert!(matches!(load(&path), Err(InputError::Empty(_))));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,411 @@
+//! Sets of integers kept as sorted, merged runs of inclusive intervals, for
+//! the puzzles that deal in ranges far too big to hold element by element.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// The integers an interval can be made of.
+pub trait Bound: Copy + Ord + fmt::Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! bound {
+    ($($t:ty),*) => {
+        $(impl Bound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+bound!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Every integer from `lo` to `hi`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub lo: T,
+    pub hi: T,
+}
+
+impl<T: Bound> Interval<T> {
+    /// `None` when `hi` comes before `lo`, since that interval is empty.
+    pub fn new(lo: T, hi: T) -> Option<Self> {
+        (lo <= hi).then_some(Interval { lo, hi })
+    }
+
+    /// How many integers it holds. That count doesn't fit in `T` when the
+    /// interval covers the whole of it, or more than half of a signed `T`,
+    /// and then this overflows; `checked_len` says so instead.
+    pub fn len(&self) -> T {
+        self.hi - self.lo + T::ONE
+    }
+
+    /// How many integers it holds, or `None` when that's too many for `T`.
+    pub fn checked_len(&self) -> Option<T> {
+        self.hi.checked_sub(self.lo)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Interval::new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// The same length of interval, starting at `lo` instead.
+    pub fn moved_to(&self, lo: T) -> Self {
+        Interval {
+            lo,
+            hi: lo + (self.hi - self.lo),
+        }
+    }
+
+    /// Whether the two overlap or sit right next to each other, so that
+    /// together they make one interval.
+    fn touches(&self, other: &Self) -> bool {
+        let (first, second) = if self.lo <= other.lo {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        first.hi == T::MAX || second.lo <= first.hi + T::ONE
+    }
+}
+
+/// A set of integers, stored as the fewest sorted intervals that cover it:
+/// no two of them overlap or even touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Sorts and merges any old pile of intervals.
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.sort();
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for i in intervals {
+            match merged.last_mut() {
+                Some(last) if last.touches(&i) => last.hi = last.hi.max(i.hi),
+                _ => merged.push(i),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalize(intervals);
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set. Overflows when that's too many for
+    /// `T`, like `Interval::len`; `checked_len` says so instead.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, i| total + i.len())
+    }
+
+    /// How many integers are in the set, or `None` when that's too many for
+    /// `T`.
+    pub fn checked_len(&self) -> Option<T> {
+        self.intervals
+            .iter()
+            .try_fold(T::ZERO, |total, i| total.checked_add(i.checked_len()?))
+    }
+
+    pub fn first(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.lo)
+    }
+
+    pub fn last(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.hi)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let after = self.intervals.partition_point(|i| i.hi < x);
+        self.intervals.get(after).is_some_and(|i| i.contains(x))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let all = self.intervals.iter().chain(&other.intervals).copied();
+        IntervalSet::normalize(all.collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (0, 0);
+        let mut both = Vec::new();
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (&self.intervals[a], &other.intervals[b]);
+            both.extend(x.intersection(y));
+            if x.hi < y.hi {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals: both }
+    }
+
+    /// Everything in `self` that isn't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut left = Vec::new();
+        let mut cuts = other.intervals.iter().peekable();
+        for &i in &self.intervals {
+            let mut rest = Some(i);
+            while let (Some(r), Some(cut)) = (rest, cuts.peek()) {
+                if cut.hi < r.lo {
+                    cuts.next();
+                    continue;
+                }
+                if cut.lo > r.hi {
+                    break;
+                }
+                if cut.lo > r.lo {
+                    left.push(Interval {
+                        lo: r.lo,
+                        hi: cut.lo - T::ONE,
+                    });
+                }
+                rest = match cut.hi < r.hi {
+                    true => Some(Interval {
+                        lo: cut.hi + T::ONE,
+                        hi: r.hi,
+                    }),
+                    false => None,
+                };
+                if rest.is_some() {
+                    cuts.next();
+                }
+            }
+            left.extend(rest);
+        }
+        IntervalSet { intervals: left }
+    }
+
+    /// Moves every element by `to - from`, so that `from` would land on
+    /// `to`. Taking two points rather than an offset lets unsigned sets
+    /// move down as well as up.
+    pub fn shift(&self, from: T, to: T) -> Self {
+        let moved = |x: T| {
+            if to >= from {
+                x + (to - from)
+            } else {
+                x - (from - to)
+            }
+        };
+        let intervals = self
+            .intervals
+            .iter()
+            .map(|i| Interval {
+                lo: moved(i.lo),
+                hi: moved(i.hi),
+            })
+            .collect();
+        IntervalSet { intervals }
+    }
+
+    /// Applies a piecewise linear map: whatever falls in a piece's source
+    /// interval moves so that the source's `lo` lands on the piece's
+    /// destination, and whatever no piece covers stays put. A number in more
+    /// than one source goes with the first of them.
+    pub fn remap<I>(&self, pieces: I) -> Self
+    where
+        I: IntoIterator<Item = (Interval<T>, T)>,
+    {
+        let mut todo = self.clone();
+        let mut done = Vec::new();
+        for (source, destination) in pieces {
+            let source = IntervalSet::from(source);
+            let hit = todo.intersection(&source);
+            done.extend(hit.shift(source.intervals[0].lo, destination).intervals);
+            todo = todo.difference(&source);
+        }
+        done.extend(todo.intervals);
+        IntervalSet::normalize(done)
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::normalize(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+    use std::collections::BTreeSet;
+
+    fn set(pairs: &[(i32, i32)]) -> IntervalSet<i32> {
+        pairs
+            .iter()
+            .map(|&(lo, hi)| Interval::new(lo, hi).unwrap())
+            .collect()
+    }
+
+    /// Small sets from arbitrary pairs, with reversed pairs flipped round,
+    /// kept to `0..128` so that lengths and remapping can't overflow.
+    fn arbitrary(pairs: &[(u8, u8)]) -> IntervalSet<u8> {
+        pairs
+            .iter()
+            .map(|&(a, b)| Interval {
+                lo: a.min(b) / 2,
+                hi: a.max(b) / 2,
+            })
+            .collect()
+    }
+
+    fn elements(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.intervals().iter().flat_map(|i| i.lo..=i.hi).collect()
+    }
+
+    fn is_normal(set: &IntervalSet<u8>) -> bool {
+        set.intervals()
+            .windows(2)
+            .all(|w| w[0].hi < w[1].lo && w[1].lo - w[0].hi > 1)
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(set(&[(8, 24), (2, 14)]), set(&[(2, 24)]));
+        assert_eq!(set(&[(2, 4), (5, 9)]).intervals().len(), 1);
+        assert_eq!(set(&[(2, 4), (6, 9)]).intervals().len(), 2);
+        assert_eq!(set(&[(2, 4), (6, 9)]).len(), 7);
+        assert!(IntervalSet::<i32>::new().is_empty());
+        assert_eq!(IntervalSet::<i32>::new().len(), 0);
+        assert!(Interval::new(3, 2).is_none());
+        let mut all = IntervalSet::from(Interval::new(250u8, 255).unwrap());
+        all.insert(Interval::new(0, 249).unwrap());
+        assert_eq!(all.intervals(), &[Interval { lo: 0, hi: 255 }]);
+    }
+
+    #[test]
+    fn test_checked_len() {
+        assert_eq!(Interval::new(3, 9).unwrap().checked_len(), Some(7));
+        assert_eq!(Interval::new(0u8, 254).unwrap().checked_len(), Some(255));
+        assert_eq!(Interval::new(0u8, 255).unwrap().checked_len(), None);
+        assert_eq!(Interval::new(-100i8, 100).unwrap().checked_len(), None);
+        assert_eq!(set(&[(2, 4), (6, 9)]).checked_len(), Some(7));
+        let mut halves = IntervalSet::from(Interval::new(-128i8, -1).unwrap());
+        halves.insert(Interval::new(1, 127).unwrap());
+        assert_eq!(halves.checked_len(), None);
+        assert_eq!(IntervalSet::<u8>::new().checked_len(), Some(0));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[(0, 5), (10, 20)]);
+        let b = set(&[(3, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (13, 20)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9)]));
+        assert_eq!(a.shift(10, 0), set(&[(-10, -5), (0, 10)]));
+        assert!(a.contains(15) && !a.contains(7));
+        assert_eq!((a.first(), a.last()), (Some(0), Some(20)));
+    }
+
+    #[test]
+    fn test_remap() {
+        let pieces = [
+            (Interval::new(98u64, 99).unwrap(), 50),
+            (Interval::new(50, 97).unwrap(), 52),
+        ];
+        let lot = |lo, hi| IntervalSet::from(Interval::new(lo, hi).unwrap());
+        assert_eq!(lot(82, 82).remap(pieces), lot(84, 84));
+        assert_eq!(lot(20, 40).remap(pieces), lot(20, 40));
+        assert_eq!(lot(40, 59).remap(pieces), lot(40, 49).union(&lot(52, 61)));
+        assert_eq!(lot(90, 100).remap(pieces), lot(50, 51).union(&lot(92, 100)));
+        assert_eq!(lot(40, 130).remap(pieces), lot(40, 130));
+    }
+
+    quickcheck! {
+        fn prop_normal(a: Vec<(u8, u8)>) -> bool {
+            let a = arbitrary(&a);
+            is_normal(&a) && a.len() as usize == elements(&a).len()
+        }
+
+        fn prop_union(a: Vec<(u8, u8)>, b: Vec<(u8, u8)>) -> bool {
+            let (a, b) = (arbitrary(&a), arbitrary(&b));
+            let union = a.union(&b);
+            is_normal(&union) && elements(&union) == &elements(&a) | &elements(&b)
+        }
+
+        fn prop_intersection(a: Vec<(u8, u8)>, b: Vec<(u8, u8)>) -> bool {
+            let (a, b) = (arbitrary(&a), arbitrary(&b));
+            let both = a.intersection(&b);
+            is_normal(&both) && elements(&both) == &elements(&a) & &elements(&b)
+        }
+
+        fn prop_difference(a: Vec<(u8, u8)>, b: Vec<(u8, u8)>) -> bool {
+            let (a, b) = (arbitrary(&a), arbitrary(&b));
+            let left = a.difference(&b);
+            is_normal(&left) && elements(&left) == &elements(&a) - &elements(&b)
+        }
+
+        fn prop_contains(a: Vec<(u8, u8)>, x: u8) -> bool {
+            let a = arbitrary(&a);
+            a.contains(x) == elements(&a).contains(&x)
+        }
+
+        fn prop_remap(a: Vec<(u8, u8)>, pieces: Vec<(u8, u8, u8)>) -> bool {
+            let a = arbitrary(&a);
+            let pieces: Vec<(Interval<u8>, u8)> = pieces
+                .iter()
+                .map(|&(x, y, d)| {
+                    let (lo, hi) = (x.min(y) / 2, x.max(y) / 2);
+                    (Interval { lo, hi }, d / 2)
+                })
+                .collect();
+            let expected: BTreeSet<u8> = elements(&a)
+                .into_iter()
+                .map(|x| match pieces.iter().find(|(s, _)| s.contains(x)) {
+                    Some((s, d)) => x - s.lo + d,
+                    None => x,
+                })
+                .collect();
+            let remapped = a.remap(pieces.iter().copied());
+            is_normal(&remapped) && elements(&remapped) == expected
+        }
+    }
+}
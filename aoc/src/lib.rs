@@ -0,0 +1,59 @@
+//! Shared plumbing for the yearly advent of code crates.
+//!
+//! - `cache` and `input`: finding a puzzle input and chopping it into lines
+//!   or paragraphs.
+//! - `parse`: reading lines, including those declared with `parse_line!`,
+//!   and saying where they were malformed with a `ParseError`.
+//! - `solution`, `calendar`, `expected` and `sample`: the `Solution` trait,
+//!   each year's register of days, its accepted answers and the sample
+//!   fixtures that become tests.
+//! - `grid` and `point`: rectangular boards, and points and directions on
+//!   integer grids.
+//! - `search`: shortest paths over a neighbour function.
+//! - `interval` and `range_map`: sets and piecewise maps of integer ranges
+//!   too big to hold element by element.
+//! - `math`: gcd and lcm, the Chinese remainder theorem and cycle detection.
+//! - `automaton`: boards that change a step at a time.
+//! - `screen` and `image`: animations in text, and pictures saved to a file.
+//! - `log`: the `debug!` and `trace!` macros.
+//! - `parallel`: maps and searches spread over rayon's pool when built with
+//!   the `parallel` feature.
+//! - `cpu` and `debugger`: a cycle-accurate machine for assembled programs,
+//!   and a step debugger over it.
+//! - `ocr`: reading block letters drawn on a screen.
+
+pub mod automaton;
+pub mod cache;
+pub mod calendar;
+pub mod cpu;
+pub mod debugger;
+pub mod expected;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod math;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod point;
+pub mod range_map;
+pub mod sample;
+pub mod screen;
+pub mod search;
+pub mod solution;
+
+pub use automaton::{Automaton, Board, Neighbourhood};
+pub use cache::{DirFetcher, Fetcher, InputCache};
+pub use calendar::{Answers, Calendar, Day};
+pub use expected::{Expected, ExpectedError, Verdict};
+pub use grid::{Grid, Pos};
+pub use image::Image;
+pub use input::{lines, load, paragraphs, InputError};
+pub use interval::{Interval, IntervalSet};
+pub use parse::{parse_lines, ParseError};
+pub use point::{Direction, Direction8, Point2, Point3};
+pub use range_map::RangeMap;
+pub use screen::Screen;
+pub use solution::{Solution, Unsolved};
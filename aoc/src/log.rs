@@ -0,0 +1,152 @@
+//! Diagnostics the days can leave in for good: `debug!` for the odd line
+//! about how a solution is getting on, `trace!` for narrating every step.
+//! Both are silent until asked for, with `-v` or `-vv` on the runner or by
+//! setting `AOC_LOG` to `debug` or `trace`, and cost a single load of the
+//! level when silent, since nothing is formatted for a level that's off.
+//! Whatever is logged goes to stderr, out of the way of the answers.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The environment variable the level is read from, when nobody sets it.
+pub const ENV: &str = "AOC_LOG";
+
+/// How much to say, each level including everything below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    Debug = 1,
+    Trace = 2,
+}
+
+/// Not set yet, so the first look reads it from `ENV`.
+const UNSET: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+impl Level {
+    /// The level for a count of `-v`s.
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "off" | "0" => Ok(Level::Off),
+            "debug" | "1" => Ok(Level::Debug),
+            "trace" | "2" => Ok(Level::Trace),
+            other => Err(format!("unknown log level `{other}`")),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Level::Off => "off",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The level set with `set_level`, or else the one in `ENV`, or else off.
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => {
+            let level = std::env::var(ENV)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(Level::Off);
+            // unless someone got there first
+            let _ =
+                LEVEL.compare_exchange(UNSET, level as u8, Ordering::Relaxed, Ordering::Relaxed);
+            Level::from_verbosity(LEVEL.load(Ordering::Relaxed))
+        }
+        level => Level::from_verbosity(level),
+    }
+}
+
+/// Whether messages at `level` are wanted.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, args: fmt::Arguments) {
+    eprintln!("[{level}] {args}");
+}
+
+/// Logs a line about how a solution is getting on, formatted like
+/// `format!`, when running with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Logs a step of a solution, formatted like `format!`, when running with
+/// `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!(" TRACE\n".parse(), Ok(Level::Trace));
+        assert_eq!("0".parse(), Ok(Level::Off));
+        assert!("loud".parse::<Level>().is_err());
+        assert_eq!(Level::from_verbosity(0), Level::Off);
+        assert_eq!(Level::from_verbosity(1), Level::Debug);
+        assert_eq!(Level::from_verbosity(3), Level::Trace);
+        assert!(Level::Debug < Level::Trace);
+        assert_eq!(Level::Trace.to_string(), "trace");
+    }
+
+    #[test]
+    fn test_enabled() {
+        // the only test that touches the level, since it's shared
+        let formatted = std::cell::Cell::new(0);
+        let noisy = || {
+            formatted.set(formatted.get() + 1);
+            "noisy"
+        };
+        set_level(Level::Debug);
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+        assert!(!enabled(Level::Off));
+        crate::trace!("{}", noisy());
+        assert_eq!(formatted.get(), 0);
+        crate::debug!("{}", noisy());
+        assert_eq!(formatted.get(), 1);
+        set_level(Level::Off);
+        crate::debug!("{}", noisy());
+        assert_eq!(formatted.get(), 1);
+    }
+}
@@ -0,0 +1,252 @@
+//! Number theory for answers too far away to simulate: find where the states
+//! start to loop, line the loops up, and jump straight to the end.
+
+use crate::interval::Bound;
+use std::fmt::Debug;
+use std::ops::{Div, Mul, Rem};
+
+/// The integer types the helpers here work over.
+pub trait Integer: Bound + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self> {}
+
+impl<T: Bound + Mul<Output = T> + Div<Output = T> + Rem<Output = T>> Integer for T {}
+
+fn abs<T: Integer>(a: T) -> T {
+    if a < T::ZERO {
+        T::ZERO - a
+    } else {
+        a
+    }
+}
+
+/// The greatest common divisor of `a` and `b`, never negative, and zero only
+/// when both are.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, never negative, and zero when
+/// either is.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+/// The greatest common divisor of everything, zero if there is nothing.
+pub fn gcd_all<T: Integer, I: IntoIterator<Item = T>>(numbers: I) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of everything, one if there is nothing.
+pub fn lcm_all<T: Integer, I: IntoIterator<Item = T>>(numbers: I) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// `(g, p, q)` with `g = gcd(a, b) = p * a + q * b`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, p, q) = extended_gcd(b, a % b);
+        (g, q, p - (a / b) * q)
+    }
+}
+
+/// The smallest non-negative `x` with `x ≡ r (mod m)` for every `(r, m)`,
+/// along with the modulus every other solution is `x` plus a multiple of.
+/// The moduli do not have to be coprime, so there is no answer when two of
+/// the congruences disagree, nor when the modulus outgrows an `i64`, nor
+/// for a modulus that isn't positive.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0_i128, 1_i128);
+    for (r, n) in congruences {
+        if n <= 0 {
+            return None;
+        }
+        let (r, n) = (r as i128, n as i128);
+        let (g, p, _) = extended_gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let t = ((r - x) / g % step) * p % step;
+        let modulus = m * step;
+        i64::try_from(modulus).ok()?;
+        x = (x + m * t).rem_euclid(modulus);
+        m = modulus;
+    }
+    Some((x as i64, m as i64))
+}
+
+/// Where the states reached by repeatedly stepping from `start` begin to go
+/// round in a loop, as `(prefix, len)`: the first `prefix` states are never
+/// seen again, and from then on state `i + len` is state `i`. Uses Brent's
+/// algorithm, so only two states are ever kept, and never returns if the
+/// states never repeat.
+pub fn find_cycle<S, F>(start: S, step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    find_cycle_by(start, step, |s| s.clone())
+}
+
+/// `find_cycle` for states that are too big to compare, or that carry things
+/// along that make no difference to where they go next: two states are the
+/// same when their `key`s are.
+pub fn find_cycle_by<S, K, F, G>(start: S, mut step: F, key: G) -> (usize, usize)
+where
+    S: Clone,
+    K: PartialEq,
+    F: FnMut(&S) -> S,
+    G: Fn(&S) -> K,
+{
+    // the hare runs ahead in ever longer sprints until it meets the tortoise
+    // waiting at the start of one, and the length of that sprint is the loop
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = key(&start);
+    let mut hare = step(&start);
+    while key(&hare) != tortoise {
+        if power == len {
+            tortoise = key(&hare);
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+    // then with the hare a loop ahead of the tortoise, they meet where it starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    (prefix, len)
+}
+
+/// The `n`th value of a sequence that, after its first `prefix` values,
+/// grows by the same amount every `len` values, as a periodic simulation's
+/// running totals do. Needs at least the first `prefix + len + 1` values in
+/// `history`; a sequence that just repeats itself grows by zero.
+pub fn extrapolate_to<T>(history: &[T], (prefix, len): (usize, usize), n: usize) -> T
+where
+    T: Integer + TryFrom<usize>,
+    <T as TryFrom<usize>>::Error: Debug,
+{
+    if n < history.len() {
+        return history[n];
+    }
+    let (cycles, rest) = ((n - prefix) / len, (n - prefix) % len);
+    let gain = history[prefix + len] - history[prefix];
+    if gain == T::ZERO {
+        return history[prefix + rest];
+    }
+    history[prefix + rest] + gain * T::try_from(cycles).expect("too many cycles to count")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0_u32, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2_u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<usize>::new()), 1);
+        assert_eq!(gcd_all(Vec::<usize>::new()), 0);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, i64::MAX), (0, i64::MAX - 1)]), None);
+        assert_eq!(crt([(1, 3), (0, 0)]), None);
+        assert_eq!(crt([(1, -3)]), None);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+        assert_eq!(find_cycle(0, |&x| if x == 7 { 3 } else { x + 1 }), (3, 5));
+        assert_eq!(find_cycle(5, |&x| x), (0, 1));
+        assert_eq!(find_cycle(0, |&x| (x + 1) % 10), (0, 10));
+        // the second half of the state makes no difference
+        let step = |&(x, noise): &(u32, u32)| ((x * x + 1) % 255, noise + 1);
+        let (prefix, len) = find_cycle_by((3, 0), step, |&(x, _)| x);
+        assert_eq!((prefix, len), find_cycle(3, |&x| (x * x + 1) % 255));
+    }
+
+    #[test]
+    fn test_extrapolate_to() {
+        // 0, 5, 8, then +1, +2, +3 over and over
+        let history = [0_i64, 5, 8, 9, 11, 14];
+        assert_eq!(extrapolate_to(&history, (2, 3), 4), 11);
+        assert_eq!(extrapolate_to(&history, (2, 3), 6), 15);
+        assert_eq!(extrapolate_to(&history, (2, 3), 7), 17);
+        assert_eq!(extrapolate_to(&history, (2, 3), 8), 20);
+        assert_eq!(extrapolate_to(&history, (2, 3), 2 + 3 * 1000), 6008);
+        // going nowhere
+        assert_eq!(extrapolate_to(&[7_u8, 1, 2, 1], (1, 2), 1000), 2);
+    }
+
+    quickcheck! {
+        fn prop_gcd_lcm(a: u16, b: u16) -> bool {
+            let (a, b) = (a as u64, b as u64);
+            let g = gcd(a, b);
+            let divides = g == 0 || (a % g == 0 && b % g == 0);
+            divides && g * lcm(a, b) == a * b
+        }
+
+        fn prop_crt(congruences: Vec<(u8, u8)>) -> bool {
+            let congruences: Vec<(i64, i64)> = congruences
+                .iter()
+                .take(4)
+                .map(|&(r, m)| (r as i64, (m % 16) as i64 + 1))
+                .collect();
+            let solves = |x: i64| congruences.iter().all(|&(r, m)| (x - r).rem_euclid(m) == 0);
+            let modulus = lcm_all(congruences.iter().map(|&(_, m)| m));
+            match crt(congruences.iter().copied()) {
+                Some((x, m)) => m == modulus && (0..x).all(|y| !solves(y)) && solves(x),
+                None => (0..modulus).all(|y| !solves(y)),
+            }
+        }
+
+        fn prop_find_cycle(table: Vec<u8>, start: u8) -> bool {
+            if table.is_empty() {
+                return true;
+            }
+            let next = |&x: &u8| table[x as usize % table.len()];
+            let (prefix, len) = find_cycle(start, next);
+            let states: Vec<u8> = std::iter::successors(Some(start), |x| Some(next(x)))
+                .take(prefix + 2 * len + 1)
+                .collect();
+            let repeats = (prefix..prefix + len).all(|i| states[i] == states[i + len]);
+            let tail = (prefix..prefix + len).all(|i| (i + 1..i + len).all(|j| states[i] != states[j]));
+            let fresh = prefix == 0 || states[prefix - 1] != states[prefix - 1 + len];
+            repeats && tail && fresh
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Reading the block letters some days draw on a screen instead of giving an
+//! answer outright. There are two fonts: the small one, four pixels wide and
+//! six tall, and the large one, six wide and ten tall. Lit pixels are `#`,
+//! anything else is dark, and letters are told apart by the dark columns
+//! between them, so the spacing doesn't matter.
+
+/// A set of letters all the same height, each drawn a row at a time.
+#[derive(Debug)]
+pub struct Font {
+    pub height: usize,
+    pub glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The four by six letters, as on the handheld in 2022 day 10 and others.
+#[rustfmt::skip]
+pub static SMALL: Font = Font {
+    height: 6,
+    glyphs: &[
+        ('A', &[".##.",
+                "#..#",
+                "#..#",
+                "####",
+                "#..#",
+                "#..#"]),
+        ('B', &["###.",
+                "#..#",
+                "###.",
+                "#..#",
+                "#..#",
+                "###."]),
+        ('C', &[".##.",
+                "#..#",
+                "#...",
+                "#...",
+                "#..#",
+                ".##."]),
+        ('E', &["####",
+                "#...",
+                "###.",
+                "#...",
+                "#...",
+                "####"]),
+        ('F', &["####",
+                "#...",
+                "###.",
+                "#...",
+                "#...",
+                "#..."]),
+        ('G', &[".##.",
+                "#..#",
+                "#...",
+                "#.##",
+                "#..#",
+                ".###"]),
+        ('H', &["#..#",
+                "#..#",
+                "####",
+                "#..#",
+                "#..#",
+                "#..#"]),
+        ('I', &[".###",
+                "..#.",
+                "..#.",
+                "..#.",
+                "..#.",
+                ".###"]),
+        ('J', &["..##",
+                "...#",
+                "...#",
+                "...#",
+                "#..#",
+                ".##."]),
+        ('K', &["#..#",
+                "#.#.",
+                "##..",
+                "#.#.",
+                "#.#.",
+                "#..#"]),
+        ('L', &["#...",
+                "#...",
+                "#...",
+                "#...",
+                "#...",
+                "####"]),
+        ('O', &[".##.",
+                "#..#",
+                "#..#",
+                "#..#",
+                "#..#",
+                ".##."]),
+        ('P', &["###.",
+                "#..#",
+                "#..#",
+                "###.",
+                "#...",
+                "#..."]),
+        ('R', &["###.",
+                "#..#",
+                "#..#",
+                "###.",
+                "#.#.",
+                "#..#"]),
+        ('S', &[".###",
+                "#...",
+                "#...",
+                ".##.",
+                "...#",
+                "###."]),
+        ('U', &["#..#",
+                "#..#",
+                "#..#",
+                "#..#",
+                "#..#",
+                ".##."]),
+        ('Y', &["#...#",
+                "#...#",
+                ".#.#.",
+                "..#..",
+                "..#..",
+                "..#.."]),
+        ('Z', &["####",
+                "...#",
+                "..#.",
+                ".#..",
+                "#...",
+                "####"]),
+    ],
+};
+
+/// The six by ten letters, as in the sky in 2018 day 10.
+#[rustfmt::skip]
+pub static LARGE: Font = Font {
+    height: 10,
+    glyphs: &[
+        ('A', &["..##..",
+                ".#..#.",
+                "#....#",
+                "#....#",
+                "#....#",
+                "######",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#....#"]),
+        ('B', &["#####.",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#####.",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#####."]),
+        ('C', &[".####.",
+                "#....#",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#....#",
+                ".####."]),
+        ('E', &["######",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#####.",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "######"]),
+        ('F', &["######",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#####.",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#....."]),
+        ('G', &[".####.",
+                "#....#",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#..###",
+                "#....#",
+                "#....#",
+                "#...##",
+                ".###.#"]),
+        ('H', &["#....#",
+                "#....#",
+                "#....#",
+                "#....#",
+                "######",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#....#"]),
+        ('J', &["...###",
+                "....#.",
+                "....#.",
+                "....#.",
+                "....#.",
+                "....#.",
+                "....#.",
+                "#...#.",
+                "#...#.",
+                ".###.."]),
+        ('K', &["#....#",
+                "#...#.",
+                "#..#..",
+                "#.#...",
+                "##....",
+                "##....",
+                "#.#...",
+                "#..#..",
+                "#...#.",
+                "#....#"]),
+        ('L', &["#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "######"]),
+        ('N', &["#....#",
+                "##...#",
+                "##...#",
+                "#.#..#",
+                "#.#..#",
+                "#..#.#",
+                "#..#.#",
+                "#...##",
+                "#...##",
+                "#....#"]),
+        ('P', &["#####.",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#####.",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#.....",
+                "#....."]),
+        ('R', &["#####.",
+                "#....#",
+                "#....#",
+                "#....#",
+                "#####.",
+                "#..#..",
+                "#...#.",
+                "#...#.",
+                "#....#",
+                "#....#"]),
+        ('X', &["#....#",
+                "#....#",
+                ".#..#.",
+                ".#..#.",
+                "..##..",
+                "..##..",
+                ".#..#.",
+                ".#..#.",
+                "#....#",
+                "#....#"]),
+        ('Z', &["######",
+                ".....#",
+                ".....#",
+                "....#.",
+                "...#..",
+                "..#...",
+                ".#....",
+                "#.....",
+                "#.....",
+                "######"]),
+    ],
+};
+
+/// Which pixels of `rows` are lit, a row at a time.
+fn pixels<S: AsRef<str>>(rows: &[S]) -> Vec<Vec<bool>> {
+    rows.iter()
+        .map(|row| row.as_ref().chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+/// Each run of columns with something lit in it, from the first column to
+/// just past the last.
+fn letters(pixels: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| pixels.iter().any(|row| row.get(x) == Some(&true));
+    let mut runs = Vec::new();
+    let mut start = None;
+    for x in 0..=width {
+        match (start, x < width && lit(x)) {
+            (None, true) => start = Some(x),
+            (Some(from), false) => {
+                runs.push((from, x));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    runs
+}
+
+/// The lit pixels of columns `from` to `to`, as text to look up.
+fn cut(pixels: &[Vec<bool>], (from, to): (usize, usize)) -> Vec<String> {
+    pixels
+        .iter()
+        .map(|row| {
+            (from..to)
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+impl Font {
+    fn glyph(&self, letter: &[String]) -> Option<char> {
+        self.glyphs.iter().find_map(|&(c, rows)| {
+            let glyph = pixels(rows);
+            let runs = letters(&glyph);
+            let (from, to) = (runs.first()?.0, runs.last()?.1);
+            (cut(&glyph, (from, to)) == letter).then_some(c)
+        })
+    }
+
+    /// The letters drawn on `rows`, or `None` unless there's exactly a
+    /// font's height of them and every shape on them is one of its letters.
+    pub fn read<S: AsRef<str>>(&self, rows: &[S]) -> Option<String> {
+        if rows.len() != self.height {
+            return None;
+        }
+        let pixels = pixels(rows);
+        let runs = letters(&pixels);
+        if runs.is_empty() {
+            return None;
+        }
+        runs.into_iter()
+            .map(|run| self.glyph(&cut(&pixels, run)))
+            .collect()
+    }
+}
+
+/// The letters drawn on `rows`, in whichever font is the right height.
+pub fn read<S: AsRef<str>>(rows: &[S]) -> Option<String> {
+    [&SMALL, &LARGE]
+        .into_iter()
+        .find(|font| font.height == rows.len())
+        .and_then(|font| font.read(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes `text` out in `font`, with `gap` dark columns between letters.
+    fn render(font: &Font, text: &str, gap: usize) -> Vec<String> {
+        let mut rows = vec![String::new(); font.height];
+        for (i, c) in text.chars().enumerate() {
+            let (_, glyph) = font.glyphs.iter().find(|(g, _)| *g == c).unwrap();
+            for (row, line) in rows.iter_mut().zip(glyph.iter()) {
+                if i > 0 {
+                    row.push_str(&".".repeat(gap));
+                }
+                row.push_str(line);
+            }
+        }
+        rows
+    }
+
+    #[test]
+    fn test_read_small() {
+        let screen = [
+            "####..##....##..##..###....##.###..####.",
+            "#....#..#....#.#..#.#..#....#.#..#.#....",
+            "###..#.......#.#..#.#..#....#.#..#.###..",
+            "#....#.......#.####.###.....#.###..#....",
+            "#....#..#.#..#.#..#.#....#..#.#.#..#....",
+            "#.....##...##..#..#.#.....##..#..#.####.",
+        ];
+        assert_eq!(read(&screen).as_deref(), Some("FCJAPJRE"));
+        assert_eq!(SMALL.read(&screen).as_deref(), Some("FCJAPJRE"));
+        assert_eq!(LARGE.read(&screen), None);
+    }
+
+    #[test]
+    fn test_read_large() {
+        let sky = render(&LARGE, "HXNZ", 2);
+        assert_eq!(read(&sky).as_deref(), Some("HXNZ"));
+    }
+
+    #[test]
+    fn test_every_letter() {
+        for font in [&SMALL, &LARGE] {
+            let alphabet: String = font.glyphs.iter().map(|&(c, _)| c).collect();
+            for gap in [1, 3] {
+                let rows = render(font, &alphabet, gap);
+                assert_eq!(font.read(&rows), Some(alphabet.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_unreadable() {
+        // the sample's stripes, which aren't letters at all
+        let stripes = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(read(&stripes), None);
+        assert_eq!(read(&["....", "...."]), None);
+        assert_eq!(read(&[""; 6]), None);
+        assert_eq!(read::<&str>(&[]), None);
+    }
+}
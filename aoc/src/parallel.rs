@@ -0,0 +1,62 @@
+//! Spreading independent pieces of work over every core. Built with the
+//! `parallel` feature the work goes to rayon's pool; without it the
+//! pieces run one after another on the calling thread. Either way the
+//! answers come back the same and in the same order, so a day reads the
+//! same whichever way it was built.
+
+use std::ops::Range;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// `f` of every item, in the order of the items.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+    #[cfg(not(feature = "parallel"))]
+    items.iter().map(f).collect()
+}
+
+/// What `f` finds for the first index in `range` it finds anything for,
+/// the first by index even when a later one happens to finish sooner.
+pub fn find_first<R, F>(range: Range<usize>, f: F) -> Option<R>
+where
+    R: Send,
+    F: Fn(usize) -> Option<R> + Sync + Send,
+{
+    // indexes are often cheap one at a time, like rows, so they're handed
+    // out in batches rather than leaving the pool mostly bookkeeping
+    #[cfg(feature = "parallel")]
+    return range.into_par_iter().with_min_len(1024).find_map_first(f);
+    #[cfg(not(feature = "parallel"))]
+    range.filter_map(f).next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = map(&items, |&n| n * n);
+        assert_eq!(squares, items.iter().map(|&n| n * n).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], |&n| n).is_empty());
+    }
+
+    #[test]
+    fn test_find_first() {
+        // every multiple of 7 past 100 would do, but only the first counts
+        let found = find_first(0..100_000, |n| {
+            (n > 100 && n.is_multiple_of(7)).then_some(n * 2)
+        });
+        assert_eq!(found, Some(210));
+        assert_eq!(find_first(0..1000, |_| None::<usize>), None);
+        assert_eq!(find_first(5..5, Some), None);
+    }
+}
@@ -0,0 +1,401 @@
+//! Reading puzzle input a line at a time, and saying exactly where it went
+//! wrong when it does.
+
+use std::str::FromStr;
+use std::{error, fmt};
+
+/// Where a puzzle input stopped making sense: the line and column (both
+/// counted from 1) of the offending text, and what was wrong with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Blames `bad` within `line`. When `bad` is a slice of `line` the column
+    /// points at it, otherwise it points at the start of the line.
+    pub fn new<R: Into<String>>(reason: R, line: &str, bad: &str) -> Self {
+        let start = line.as_ptr() as usize;
+        let at = bad.as_ptr() as usize;
+        let column = if at >= start && at + bad.len() <= start + line.len() {
+            line[..at - start].chars().count() + 1
+        } else {
+            1
+        };
+        ParseError {
+            line: 1,
+            column,
+            text: bad.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Blames the whole of `line`.
+    pub fn line<R: Into<String>>(reason: R, line: &str) -> Self {
+        ParseError::new(reason, line, line)
+    }
+
+    /// Moves the error `lines` further down, for when the text that failed
+    /// was itself a piece of a bigger input.
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: `{}`",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses every line, numbering any error by the line it came from.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.below(i)))
+        .collect()
+}
+
+/// One piece of a `scan` pattern: text that has to be there word for word,
+/// or a `{name}` standing for whatever is there instead.
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+fn pieces(pattern: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => literal.push(chars.next().unwrap()),
+            '}' if chars.peek() == Some(&'}') => literal.push(chars.next().unwrap()),
+            '{' => {
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                if let Some(Piece::Field(_)) = pieces.last() {
+                    panic!("`{pattern}` has two fields in a row with nothing to tell them apart");
+                }
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                pieces.push(Piece::Field(name));
+            }
+            _ => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    pieces
+}
+
+/// Matches `line` against a pattern of literal text and `{name}` fields,
+/// returning each field's name with the text it matched. A field matches as
+/// little as it can, but never nothing: it stops at the first place the text
+/// after it in the pattern turns up, or at the end of the line if it is last.
+/// `{{` and `}}` stand for literal braces.
+pub fn scan<'a>(pattern: &str, line: &'a str) -> Result<Vec<(String, &'a str)>, ParseError> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+    let mut pending: Option<String> = None;
+    for piece in pieces(pattern) {
+        match piece {
+            Piece::Literal(text) => {
+                let expected = || ParseError::new(format!("expected `{text}`"), line, rest);
+                match pending.take() {
+                    Some(name) => {
+                        let first = rest.chars().next().ok_or_else(expected)?.len_utf8();
+                        let end = first + rest[first..].find(&text).ok_or_else(expected)?;
+                        fields.push((name, &rest[..end]));
+                        rest = &rest[end + text.len()..];
+                    }
+                    None => {
+                        // blame the first character that differs
+                        let same = rest
+                            .char_indices()
+                            .zip(text.chars())
+                            .find(|&((_, a), b)| a != b)
+                            .map_or(rest.len().min(text.len()), |((i, _), _)| i);
+                        rest = match rest.strip_prefix(text.as_str()) {
+                            Some(rest) => rest,
+                            None => {
+                                let reason = format!("expected `{text}`");
+                                return Err(ParseError::new(reason, line, &rest[same..]));
+                            }
+                        };
+                    }
+                }
+            }
+            Piece::Field(name) => pending = Some(name),
+        }
+    }
+    if let Some(name) = pending {
+        if rest.is_empty() {
+            return Err(ParseError::new(format!("missing {name}"), line, rest));
+        }
+        fields.push((name, rest));
+        rest = &rest[rest.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::new("unexpected trailing text", line, rest));
+    }
+    Ok(fields)
+}
+
+/// Parses the text `scan` found for the field called `name`, blaming it if
+/// it is not a `what`.
+pub fn field<T>(
+    line: &str,
+    fields: &[(String, &str)],
+    name: &str,
+    what: &str,
+) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let text = fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .unwrap_or_else(|| panic!("the pattern has no {{{name}}}"))
+        .1;
+    text.parse()
+        .map_err(|e| ParseError::new(format!("not a {what}: {e}"), line, text))
+}
+
+/// One way of reading a line, as `first_of` takes them.
+pub type Reader<T> = dyn Fn(&str) -> Result<T, ParseError>;
+
+/// Tries each way of reading `line` in turn, and when none of them work,
+/// blames whichever got furthest before failing.
+pub fn first_of<T>(line: &str, readers: &[&Reader<T>]) -> Result<T, ParseError> {
+    let mut furthest: Option<ParseError> = None;
+    for read in readers {
+        match read(line) {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                if furthest.as_ref().is_none_or(|f| e.column > f.column) {
+                    furthest = Some(e);
+                }
+            }
+        }
+    }
+    Err(furthest.expect("there is at least one way to read a line"))
+}
+
+/// Declares a struct, or an enum of struct-like variants, that parses itself
+/// from a line matching a `scan` pattern, with each `{field}` in the pattern
+/// parsed into the struct field of the same name:
+///
+/// ```
+/// aoc::parse_line! {
+///     #[derive(Debug, PartialEq)]
+///     struct Reading {
+///         sx: i32,
+///         sy: i32,
+///     } = "Sensor at x={sx}, y={sy}";
+/// }
+///
+/// aoc::parse_line! {
+///     #[derive(Debug, PartialEq)]
+///     enum Job {
+///         Yell { name: String, number: i64 } = "{name}: {number}",
+///         Wait { name: String, a: String, op: char, b: String } = "{name}: {a} {op} {b}",
+///     }
+/// }
+///
+/// let r: Reading = "Sensor at x=2, y=-18".parse().unwrap();
+/// assert_eq!(r, Reading { sx: 2, sy: -18 });
+/// let j: Job = "root: pppw + sjmn".parse().unwrap();
+/// assert!(matches!(j, Job::Wait { op: '+', .. }));
+/// let e = "Sensor at x=2, y=x".parse::<Reading>().unwrap_err();
+/// assert_eq!((e.column, e.reason.as_str()), (18, "not a i32: invalid digit found in string"));
+/// ```
+///
+/// An enum tries its variants in order and takes the first that fits.
+#[macro_export]
+macro_rules! parse_line {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $ty:ty),* $(,)?
+        } = $pattern:literal;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, $crate::ParseError> {
+                $crate::parse_line!(@read s, $pattern, $name { $($field: $ty),* })
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident { $($field:ident: $ty:ty),* $(,)? } = $pattern:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($variant { $($field: $ty),* }),+
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, $crate::ParseError> {
+                $crate::parse::first_of(s, &[$(
+                    &|s: &str| $crate::parse_line!(@read s, $pattern, $name::$variant { $($field: $ty),* })
+                ),+])
+            }
+        }
+    };
+    (@read $s:ident, $pattern:literal, $($path:ident)::+ { $($field:ident: $ty:ty),* }) => {{
+        let _fields = $crate::parse::scan($pattern, $s)?;
+        Ok($($path)::+ {
+            $($field: $crate::parse::field::<$ty>($s, &_fields, stringify!($field), stringify!($ty))?),*
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(u8, u8);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, ParseError> {
+            let (a, b) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::line("expected a pair", s))?;
+            let number = |t: &str| t.parse().map_err(|_| ParseError::new("not a number", s, t));
+            Ok(Pair(number(a)?, number(b)?))
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let pairs: Vec<Pair> = parse_lines("1,2\n3,4").unwrap();
+        assert_eq!(pairs, vec![Pair(1, 2), Pair(3, 4)]);
+        let e = parse_lines::<Pair>("1,2\n3,4\n5,x6").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+        assert_eq!(e.text, "x6");
+        assert_eq!(e.to_string(), "line 3, column 3: not a number: `x6`");
+        let e = parse_lines::<Pair>("1,2\n34").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.reason, "expected a pair");
+    }
+
+    parse_line! {
+        #[derive(Debug, PartialEq)]
+        struct Assignment {
+            a: u32,
+            b: u32,
+            c: u32,
+            d: u32,
+        } = "{a}-{b},{c}-{d}";
+    }
+
+    parse_line! {
+        #[derive(Debug, PartialEq)]
+        enum Command {
+            Cd { dir: String } = "$ cd {dir}",
+            Ls {} = "$ ls",
+            File { size: u64, name: String } = "{size} {name}",
+        }
+    }
+
+    #[test]
+    fn test_scan() {
+        let fields = scan("{a}-{b},{{{c}}}", "2-4,{6-8}").unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                ("a".to_string(), "2"),
+                ("b".to_string(), "4"),
+                ("c".to_string(), "6-8")
+            ]
+        );
+        let e = scan("{a}-{b}", "2-").unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (3, "missing b"));
+        let e = scan("{a}-{b}", "24").unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (1, "expected `-`"));
+        let e = scan("x={a}!", "x=1!?").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (5, "?"));
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let pairs: Vec<Assignment> = parse_lines("2-4,6-8\n12-30,1-3").unwrap();
+        assert_eq!(
+            pairs[1],
+            Assignment {
+                a: 12,
+                b: 30,
+                c: 1,
+                d: 3
+            }
+        );
+        let e = parse_lines::<Assignment>("2-4,6-8\n2-4,-6-8").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "-6"));
+        assert_eq!(e.reason, "not a u32: invalid digit found in string");
+    }
+
+    #[test]
+    fn test_parse_line_enum() {
+        let commands: Vec<Command> = parse_lines("$ cd /\n$ ls\n14848514 b.txt").unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                Command::Cd {
+                    dir: "/".to_string()
+                },
+                Command::Ls {},
+                Command::File {
+                    size: 14848514,
+                    name: "b.txt".to_string()
+                },
+            ]
+        );
+        // each variant fails somewhere, and the one that got furthest is blamed
+        let e = "$ cd".parse::<Command>().unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (5, "expected `$ cd `"));
+        let e = "$ lsx".parse::<Command>().unwrap_err();
+        assert_eq!(
+            (e.column, e.reason.as_str()),
+            (5, "unexpected trailing text")
+        );
+        let e = "dir a".parse::<Command>().unwrap_err();
+        assert_eq!((e.column, e.reason.as_str()), (1, "expected `$ cd `"));
+    }
+
+    #[test]
+    fn test_column_outside_line() {
+        let e = ParseError::new("missing", "abc", "zzz");
+        assert_eq!(e.column, 1);
+        let line = "añb";
+        assert_eq!(ParseError::new("bad", line, &line[3..]).column, 3);
+    }
+}
@@ -0,0 +1,440 @@
+//! Points on integer grids in two and three dimensions, the compass
+//! directions between them and their neighbourhoods. Directions follow the
+//! same convention as `Grid`: `y` grows downwards, so north is `y - 1`.
+
+use crate::interval::Bound;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// The coordinates that can step in every direction, not just forwards.
+pub trait Signed: Bound + Mul<Output = Self> + Neg<Output = Self> {
+    fn signum(self) -> Self {
+        match self.cmp(&Self::ZERO) {
+            std::cmp::Ordering::Less => -Self::ONE,
+            std::cmp::Ordering::Equal => Self::ZERO,
+            std::cmp::Ordering::Greater => Self::ONE,
+        }
+    }
+}
+
+impl<T: Bound + Mul<Output = T> + Neg<Output = T>> Signed for T {}
+
+fn distance<T: Bound>(a: T, b: T) -> T {
+    a.max(b) - a.min(b)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Bound> Point2<T> {
+    /// Steps along the axes, as a rook would count them.
+    pub fn manhattan(&self, other: &Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// Steps in any of eight directions, as a king would count them.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        distance(self.x, other.x).max(distance(self.y, other.y))
+    }
+
+    /// The smallest of each coordinate, the corner of the box around both.
+    pub fn min_each(&self, other: &Self) -> Self {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// The largest of each coordinate, the corner of the box around both.
+    pub fn max_each(&self, other: &Self) -> Self {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T: Signed> Point2<T> {
+    /// One step at most along each axis, towards wherever this points.
+    pub fn signum(&self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four points sharing an edge, clockwise from north.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /// The eight points sharing an edge or a corner, clockwise from north.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+}
+
+impl<T: Bound> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y) + distance(self.z, other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let (x, y, z) = (
+            distance(self.x, other.x),
+            distance(self.y, other.y),
+            distance(self.z, other.z),
+        );
+        x.max(y).max(z)
+    }
+
+    pub fn min_each(&self, other: &Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn max_each(&self, other: &Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    pub fn signum(&self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points sharing a face: down and then up along x, y and z.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        (0..3).flat_map(move |axis| {
+            [-T::ONE, T::ONE].into_iter().map(move |step| {
+                let mut next = self;
+                next[axis] = next[axis] + step;
+                next
+            })
+        })
+    }
+
+    /// The twenty six points sharing a face, an edge or a corner.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |x| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |z| Point3::new(x, y, z)))
+            })
+            .filter(|d| *d != Point3::new(T::ZERO, T::ZERO, T::ZERO))
+            .map(move |d| self + d)
+    }
+}
+
+macro_rules! arithmetic {
+    ($point:ident { $($axis:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, k: T) -> Self {
+                $point { $($axis: self.$axis * k),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),* }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+arithmetic!(Point2 { x, y });
+arithmetic!(Point3 { x, y, z });
+
+/// Coordinates by axis number, for code that works along any axis.
+impl<T> Index<usize> for Point2<T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("a 2d point has no axis {axis}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Point2<T> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("a 2d point has no axis {axis}"),
+        }
+    }
+}
+
+impl<T> Index<usize> for Point3<T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("a 3d point has no axis {axis}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Point3<T> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("a 3d point has no axis {axis}"),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// The four ways along the axes, clockwise from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// The eight ways along the axes and diagonals, clockwise from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn turned(self, quarters: usize) -> Self {
+        Direction::ALL[(self as usize + quarters) % 4]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turned(3)
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turned(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turned(2)
+    }
+
+    /// One step this way.
+    pub fn offset<T: Signed>(self) -> Point2<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    fn turned(self, eighths: usize) -> Self {
+        Direction8::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// An eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turned(7)
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turned(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turned(4)
+    }
+
+    pub fn offset<T: Signed>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let (x, y) = match self {
+            Direction8::North => (zero, -one),
+            Direction8::NorthEast => (one, -one),
+            Direction8::East => (one, zero),
+            Direction8::SouthEast => (one, one),
+            Direction8::South => (zero, one),
+            Direction8::SouthWest => (-one, one),
+            Direction8::West => (-one, zero),
+            Direction8::NorthWest => (-one, -one),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point2::new(3, -4);
+        assert_eq!(p + Point2::new(1, 1), Point2::new(4, -3));
+        assert_eq!(p - Point2::new(1, 1), Point2::new(2, -5));
+        assert_eq!(p * 2, Point2::new(6, -8));
+        assert_eq!(-p, Point2::new(-3, 4));
+        p += Point2::new(0, 4);
+        assert_eq!(p, Point2::new(3, 0));
+        p -= Point2::new(3, 0);
+        assert_eq!(p, Point2::default());
+        let q = Point3::new(1u8, 2, 3);
+        assert_eq!(q + q, Point3::new(2, 4, 6));
+        assert_eq!((q[0], q[1], q[2]), (1, 2, 3));
+        assert_eq!(Point2::from((1, 2)), Point2::new(1, 2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(1, 5), Point2::new(4, 1));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(Point2::new(2u32, 2).manhattan(&Point2::new(0, 5)), 5);
+        let (c, d) = (Point3::new(0, 0, 0), Point3::new(-1, 2, -3));
+        assert_eq!(c.manhattan(&d), 6);
+        assert_eq!(c.chebyshev(&d), 3);
+        assert_eq!(a.min_each(&b), Point2::new(1, 1));
+        assert_eq!(c.max_each(&d), Point3::new(0, 2, 0));
+        assert_eq!(Point2::new(-7, 0).signum(), Point2::new(-1, 0));
+    }
+
+    #[test]
+    fn test_directions() {
+        use Direction::*;
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(East.opposite(), West);
+        assert_eq!(South.offset::<i32>(), Point2::new(0, 1));
+        for d in Direction::ALL {
+            assert_eq!(d.offset::<i32>() + d.opposite().offset(), Point2::default());
+            assert_eq!(d.turn_left().turn_right(), d);
+        }
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::from(West), Direction8::West);
+        assert_eq!(Direction8::SouthEast.opposite(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.offset::<i8>(), Point2::new(-1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(5, 5);
+        let four: Vec<_> = p.neighbours4().collect();
+        assert_eq!(four[0], Point2::new(5, 4));
+        assert_eq!(four[1], Point2::new(6, 5));
+        assert!(four.iter().all(|n| n.manhattan(&p) == 1));
+        let eight: HashSet<_> = p.neighbours8().collect();
+        assert_eq!(eight.len(), 8);
+        assert!(eight.iter().all(|n| n.chebyshev(&p) == 1));
+        let q = Point3::new(0i64, 0, 0);
+        let six: Vec<_> = q.neighbours6().collect();
+        assert_eq!(six[0], Point3::new(-1, 0, 0));
+        assert_eq!(six[5], Point3::new(0, 0, 1));
+        assert!(six.iter().all(|n| n.manhattan(&q) == 1));
+        let twenty_six: HashSet<_> = q.neighbours26().collect();
+        assert_eq!(twenty_six.len(), 26);
+        assert!(twenty_six.iter().all(|n| n.chebyshev(&q) == 1));
+    }
+}
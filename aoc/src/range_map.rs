@@ -0,0 +1,302 @@
+//! Piecewise maps of the integers onto themselves: each piece moves one
+//! interval somewhere else wholesale, and every number no piece covers is left
+//! where it is. Maps like that compose into maps like that, so a whole chain
+//! of them can be worked out once and then queried as one.
+
+use crate::interval::{Bound, Interval, IntervalSet};
+use std::fmt;
+
+/// The pieces are kept sorted by source, never overlap, never map a number
+/// to itself, and two that could be one piece are one piece.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Bound> RangeMap<T> {
+    /// The map that leaves everything where it is.
+    pub fn identity() -> Self {
+        RangeMap { pieces: Vec::new() }
+    }
+
+    /// Sorts and tidies pieces that are already known not to overlap.
+    fn normalize(mut pieces: Vec<(Interval<T>, T)>) -> Self {
+        pieces.retain(|&(source, destination)| source.lo != destination);
+        pieces.sort();
+        let mut merged: Vec<(Interval<T>, T)> = Vec::with_capacity(pieces.len());
+        for (source, destination) in pieces {
+            if let Some((last, start)) = merged.last_mut() {
+                let end = last.moved_to(*start).hi;
+                if last.hi < T::MAX
+                    && end < T::MAX
+                    && last.hi + T::ONE == source.lo
+                    && end + T::ONE == destination
+                {
+                    last.hi = source.hi;
+                    continue;
+                }
+            }
+            merged.push((source, destination));
+        }
+        RangeMap { pieces: merged }
+    }
+
+    /// Moves `source` so that it starts at `destination`. Whatever part of
+    /// `source` the map already moves keeps going where it did.
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        let pieces = std::mem::take(&mut self.pieces);
+        *self = pieces.into_iter().chain([(source, destination)]).collect();
+    }
+
+    /// Each source interval with where its `lo` goes.
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    /// Where `x` goes.
+    pub fn get(&self, x: T) -> T {
+        let after = self.pieces.partition_point(|(source, _)| source.hi < x);
+        match self.pieces.get(after) {
+            Some(&(source, destination)) if source.contains(x) => destination + (x - source.lo),
+            _ => x,
+        }
+    }
+
+    /// Every number the map moves.
+    pub fn sources(&self) -> IntervalSet<T> {
+        self.pieces.iter().map(|&(source, _)| source).collect()
+    }
+
+    /// `set` cut wherever the map's pieces start or stop, with where each
+    /// cut's `lo` goes, including the cuts that stay put.
+    fn cut(&self, set: &IntervalSet<T>) -> Vec<(Interval<T>, T)> {
+        let mut cuts: Vec<(Interval<T>, T)> = set
+            .difference(&self.sources())
+            .intervals()
+            .iter()
+            .map(|&part| (part, part.lo))
+            .collect();
+        for &(source, destination) in &self.pieces {
+            for part in set.intersection(&IntervalSet::from(source)).intervals() {
+                cuts.push((*part, destination + (part.lo - source.lo)));
+            }
+        }
+        cuts
+    }
+
+    /// Where everything in `set` goes.
+    pub fn push(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        self.cut(set)
+            .into_iter()
+            .map(|(part, destination)| part.moved_to(destination))
+            .collect()
+    }
+
+    /// The one map that does `self` and then `next`.
+    pub fn then(&self, next: &RangeMap<T>) -> RangeMap<T> {
+        let mut pieces = Vec::new();
+        for &(source, destination) in &self.pieces {
+            let image = IntervalSet::from(source.moved_to(destination));
+            for (part, onward) in next.cut(&image) {
+                let start = source.lo + (part.lo - destination);
+                pieces.push((part.moved_to(start), onward));
+            }
+        }
+        // where `self` leaves things be, `next` has the last word
+        let untouched = next.sources().difference(&self.sources());
+        pieces.extend(next.cut(&untouched));
+        RangeMap::normalize(pieces)
+    }
+
+    /// The map that undoes this one, if there is one: the pieces have to
+    /// shuffle the numbers they move among themselves, or else two numbers
+    /// would end up in the same place.
+    pub fn invert(&self) -> Option<RangeMap<T>> {
+        let mut images: Vec<Interval<T>> = self
+            .pieces
+            .iter()
+            .map(|&(source, destination)| source.moved_to(destination))
+            .collect();
+        images.sort();
+        let disjoint = images.windows(2).all(|w| w[0].hi < w[1].lo);
+        let images: IntervalSet<T> = images.into_iter().collect();
+        if !disjoint || images != self.sources() {
+            return None;
+        }
+        let pieces = self
+            .pieces
+            .iter()
+            .map(|&(source, destination)| (source.moved_to(destination), source.lo))
+            .collect();
+        Some(RangeMap::normalize(pieces))
+    }
+}
+
+impl<T: Bound> FromIterator<(Interval<T>, T)> for RangeMap<T> {
+    /// Pieces that overlap ones before them only move what is left over.
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut claimed = IntervalSet::new();
+        let mut pieces = Vec::new();
+        for (source, destination) in iter {
+            let fresh = IntervalSet::from(source).difference(&claimed);
+            pieces.extend(
+                fresh
+                    .intervals()
+                    .iter()
+                    .map(|part| (*part, destination + (part.lo - source.lo))),
+            );
+            claimed.insert(source);
+        }
+        RangeMap::normalize(pieces)
+    }
+}
+
+/// One piece per line, as `lo..=hi -> lo..=hi`.
+impl<T: Bound + fmt::Display> fmt::Display for RangeMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &(source, destination) in &self.pieces {
+            let image = source.moved_to(destination);
+            writeln!(
+                f,
+                "{}..={} -> {}..={}",
+                source.lo, source.hi, image.lo, image.hi
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    fn map(pieces: &[(u64, u64, u64)]) -> RangeMap<u64> {
+        pieces
+            .iter()
+            .map(|&(lo, hi, destination)| (Interval { lo, hi }, destination))
+            .collect()
+    }
+
+    fn set(pairs: &[(u64, u64)]) -> IntervalSet<u64> {
+        pairs.iter().map(|&(lo, hi)| Interval { lo, hi }).collect()
+    }
+
+    /// Small maps from arbitrary triples, kept to `0..128` so nothing can
+    /// overflow even after two of them.
+    fn arbitrary(pieces: &[(u8, u8, u8)]) -> RangeMap<u8> {
+        pieces
+            .iter()
+            .map(|&(a, b, destination)| {
+                let (lo, hi) = (a.min(b) / 2, a.max(b) / 2);
+                (Interval { lo, hi }, destination / 2)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let swapped = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(swapped.pieces().len(), 2);
+        assert_eq!(swapped.get(98), 50);
+        assert_eq!(swapped.get(79), 81);
+        assert_eq!(swapped.get(100), 100);
+        assert_eq!(map(&[(15, 51, 0), (52, 53, 37)]), map(&[(15, 53, 0)]));
+        assert_eq!(map(&[(3, 9, 3)]), RangeMap::identity());
+        assert_eq!(
+            map(&[(0, 9, 20), (5, 14, 50)]),
+            map(&[(0, 9, 20), (10, 14, 55)])
+        );
+    }
+
+    #[test]
+    fn test_push() {
+        let swapped = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(
+            swapped.push(&set(&[(79, 92), (55, 67)])),
+            set(&[(57, 69), (81, 94)])
+        );
+        assert_eq!(
+            swapped.push(&set(&[(90, 100)])),
+            set(&[(50, 51), (92, 100)])
+        );
+        assert_eq!(swapped.push(&set(&[(40, 130)])), set(&[(40, 130)]));
+    }
+
+    #[test]
+    fn test_then() {
+        let first = map(&[(0, 9, 100)]);
+        let second = map(&[(105, 200, 0), (0, 4, 300)]);
+        let both = first.then(&second);
+        assert_eq!(both.get(3), 103);
+        assert_eq!(both.get(7), 2);
+        assert_eq!(both.get(2), 102);
+        assert_eq!(both.get(150), 45);
+        assert_eq!(both.get(100), 100);
+        assert_eq!(both.get(20), 20);
+        assert_eq!(first.then(&RangeMap::identity()), first);
+    }
+
+    #[test]
+    fn test_invert() {
+        let swapped = map(&[(98, 99, 50), (50, 97, 52)]);
+        let back = swapped.invert().unwrap();
+        assert_eq!(back, map(&[(50, 51, 98), (52, 99, 50)]));
+        assert_eq!(swapped.then(&back), RangeMap::identity());
+        assert!(map(&[(0, 9, 100)]).invert().is_none());
+        assert!(map(&[(0, 9, 20), (20, 29, 5)]).invert().is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        let swapped = map(&[(98, 99, 50), (50, 97, 52)]);
+        assert_eq!(
+            swapped.to_string(),
+            "50..=97 -> 52..=99\n98..=99 -> 50..=51\n"
+        );
+    }
+
+    quickcheck! {
+        fn prop_get(pieces: Vec<(u8, u8, u8)>, x: u8) -> bool {
+            let x = x / 2;
+            let expected = pieces
+                .iter()
+                .map(|&(a, b, d)| (a.min(b) / 2, a.max(b) / 2, d / 2))
+                .find(|&(lo, hi, _)| lo <= x && x <= hi)
+                .map_or(x, |(lo, _, d)| x - lo + d);
+            arbitrary(&pieces).get(x) == expected
+        }
+
+        fn prop_then(a: Vec<(u8, u8, u8)>, b: Vec<(u8, u8, u8)>) -> bool {
+            let (a, b) = (arbitrary(&a), arbitrary(&b));
+            let both = a.then(&b);
+            (0..=u8::MAX).all(|x| both.get(x) == b.get(a.get(x)))
+        }
+
+        fn prop_push(pieces: Vec<(u8, u8, u8)>, set: Vec<(u8, u8)>) -> bool {
+            let map = arbitrary(&pieces);
+            let set: IntervalSet<u8> = set
+                .iter()
+                .map(|&(a, b)| Interval { lo: a.min(b) / 2, hi: a.max(b) / 2 })
+                .collect();
+            let pushed = map.push(&set);
+            let elements = set.intervals().iter().flat_map(|i| i.lo..=i.hi);
+            let expected: IntervalSet<u8> = elements
+                .map(|x| Interval { lo: map.get(x), hi: map.get(x) })
+                .collect();
+            pushed == expected
+        }
+
+        fn prop_invert(pieces: Vec<(u8, u8, u8)>) -> bool {
+            let map = arbitrary(&pieces);
+            match map.invert() {
+                Some(back) => (0..=u8::MAX).all(|x| back.get(map.get(x)) == x),
+                None => {
+                    let mut seen = std::collections::HashSet::new();
+                    !(0..=u8::MAX).all(|x| seen.insert(map.get(x)))
+                        || (0..=u8::MAX).any(|x| !seen.contains(&x))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Samples from the puzzle text, kept next to the real inputs as
+//! `input/NNN-sample-K.txt`, with the answers the puzzle gives for them in a
+//! sidecar `input/NNN-sample-K.toml`:
+//!
+//! ```toml
+//! part1 = "13140"
+//! ```
+//!
+//! Each year's build script hands its `input/` directory to `write_tests`,
+//! which turns every fixture into one `#[test]` per part, so a new sample
+//! case is a new pair of files rather than a new test function. A part with
+//! no answer in the sidecar still gets its test, but ignored.
+
+use crate::expected::{ExpectedError, Record};
+use crate::Solution;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// One sample input and whatever answers are recorded for it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub case: u32,
+    pub input: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Fixture {
+    fn name(&self) -> String {
+        format!("{:03}-sample-{}", self.day, self.case)
+    }
+
+    fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Splits `NNN-sample-K.txt` into its day and case.
+fn fixture_name(file: &str) -> Option<(u8, u32)> {
+    let (day, case) = file.strip_suffix(".txt")?.split_once("-sample-")?;
+    match (day.len(), day.parse(), case.parse()) {
+        (3, Ok(day @ 1..=25), Ok(case)) => Some((day, case)),
+        _ => None,
+    }
+}
+
+/// Every fixture in `dir`, by day and then case. Files not named like a
+/// fixture are left alone, and a fixture without a sidecar has no answers.
+pub fn fixtures<P: AsRef<Path>>(dir: P) -> Result<Vec<Fixture>, ExpectedError> {
+    let dir = dir.as_ref();
+    let io = |source| ExpectedError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+    let mut fixtures = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io)? {
+        let input = entry.map_err(io)?.path();
+        let Some((day, case)) = input
+            .file_name()
+            .and_then(|f| f.to_str())
+            .and_then(fixture_name)
+        else {
+            continue;
+        };
+        let sidecar = input.with_extension("toml");
+        let record = match fs::read_to_string(&sidecar) {
+            Ok(text) => toml::from_str(&text).map_err(ExpectedError::Toml)?,
+            Err(_) if !sidecar.exists() => Record::default(),
+            Err(source) => {
+                return Err(ExpectedError::Io {
+                    path: sidecar,
+                    source,
+                })
+            }
+        };
+        fixtures.push(Fixture {
+            day,
+            case,
+            input,
+            part1: record.part1,
+            part2: record.part2,
+        });
+    }
+    fixtures.sort_by_key(|f| (f.day, f.case));
+    Ok(fixtures)
+}
+
+/// The source of the generated tests, meant to be `include!`d inside the
+/// year's crate where `dayNNN::DayNNN` names each day's solution.
+pub fn tests(fixtures: &[Fixture]) -> String {
+    let mut code = String::new();
+    for fixture in fixtures {
+        for part in [1, 2] {
+            let day = format!("{:03}", fixture.day);
+            let name = format!("day{day}_sample_{}_part{part}", fixture.case);
+            let expected = match fixture.answer(part) {
+                Some(answer) => answer,
+                None => {
+                    let reason = format!("no part {part} answer in {}.toml", fixture.name());
+                    writeln!(code, "#[ignore = {reason:?}]").unwrap();
+                    ""
+                }
+            };
+            let input = fixture.input.display().to_string();
+            writeln!(
+                code,
+                r#"#[test]
+fn {name}() {{
+    aoc::sample::check::<crate::day{day}::Day{day}>(include_str!({input:?}), {part}, {expected:?});
+}}
+"#
+            )
+            .unwrap();
+        }
+    }
+    code
+}
+
+/// For build scripts: generates the tests for the fixtures in `dir` into
+/// `$OUT_DIR/samples.rs`, and has cargo run the script again whenever a
+/// fixture comes, goes or changes.
+pub fn write_tests<P: AsRef<Path>>(dir: P) {
+    let dir = dir.as_ref();
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let dir = fs::canonicalize(dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display()));
+    let fixtures = fixtures(&dir).unwrap_or_else(|e| panic!("{e}"));
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("only build scripts have an OUT_DIR"));
+    fs::write(out.join("samples.rs"), tests(&fixtures)).expect("could not write samples.rs");
+}
+
+/// What the generated tests run: the answer for `part` of `input` has to
+/// match `expected`, ignoring surrounding whitespace like `Verdict` does.
+pub fn check<S: Solution>(input: &str, part: u8, expected: &str) {
+    let solution = S::parse(input).unwrap_or_else(|e| panic!("sample does not parse: {e}"));
+    let got = match part {
+        1 => solution.part1().to_string(),
+        _ => solution.part2().to_string(),
+    };
+    assert_eq!(got.trim(), expected.trim());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Unsolved};
+
+    struct Count(usize);
+
+    impl Solution for Count {
+        type Part1 = usize;
+        type Part2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            Ok(Count(input.lines().count()))
+        }
+        fn part1(&self) -> usize {
+            self.0
+        }
+        fn part2(&self) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_fixture_name() {
+        assert_eq!(fixture_name("010-sample-1.txt"), Some((10, 1)));
+        assert_eq!(fixture_name("022-sample-12.txt"), Some((22, 12)));
+        assert_eq!(fixture_name("010-sample-1.toml"), None);
+        assert_eq!(fixture_name("010.txt"), None);
+        assert_eq!(fixture_name("10-sample-1.txt"), None);
+        assert_eq!(fixture_name("026-sample-1.txt"), None);
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let dir = env::temp_dir().join(format!("aoc-samples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("003-sample-2.txt"), "a\nb\n").unwrap();
+        fs::write(dir.join("003-sample-2.toml"), "part1 = \"2\"\n").unwrap();
+        fs::write(dir.join("003-sample-1.txt"), "a\n").unwrap();
+        fs::write(dir.join("003.txt"), "real input\n").unwrap();
+
+        let found = fixtures(&dir).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].case, found[0].part1.as_deref()), (1, None));
+        assert_eq!((found[1].case, found[1].part1.as_deref()), (2, Some("2")));
+
+        let code = tests(&found);
+        assert_eq!(code.matches("#[test]").count(), 4);
+        assert_eq!(code.matches("#[ignore").count(), 3);
+        assert!(code.contains("fn day003_sample_2_part1() {"));
+        assert!(code.contains("check::<crate::day003::Day003>("));
+
+        fs::write(dir.join("003-sample-1.toml"), "part3 = \"1\"\n").unwrap();
+        assert!(matches!(fixtures(&dir), Err(ExpectedError::Toml(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check() {
+        check::<Count>("a\nb\nc\n", 1, "3");
+        check::<Count>("a\n", 2, "unsolved\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "left: \"3\"")]
+    fn test_check_mismatch() {
+        check::<Count>("a\nb\nc\n", 1, "4");
+    }
+}
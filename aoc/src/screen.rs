@@ -0,0 +1,32 @@
+//! Where simulations get drawn, one frame at a time. The days only say what
+//! each frame looks like; whether it ends up animated in a terminal or
+//! written to a file is up to the runner.
+
+/// Shows frames of an animation as a day produces them.
+pub trait Screen {
+    /// Shows the next frame, a picture in text, and says whether to keep
+    /// going: a day should stop drawing as soon as the viewer has had enough.
+    fn show(&mut self, frame: &str) -> bool;
+}
+
+/// Keeps every frame, for tests to look through.
+impl Screen for Vec<String> {
+    fn show(&mut self, frame: &str) -> bool {
+        self.push(frame.to_owned());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_screen() {
+        let mut frames = Vec::new();
+        let screen: &mut dyn Screen = &mut frames;
+        assert!(screen.show("#.\n.#\n"));
+        assert!(screen.show(".#\n#.\n"));
+        assert_eq!(frames, ["#.\n.#\n", ".#\n#.\n"]);
+    }
+}
@@ -0,0 +1,253 @@
+//! Shortest paths over any graph a puzzle can describe with a neighbour
+//! function, so days only have to say what a node is and where it leads.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A cheapest route, `nodes` running from the start to the goal inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+/// Every node seen so far gets an index, so the frontier and the back links
+/// can be plain vectors and the node type only needs `Hash + Eq`.
+struct Nodes<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    cost: Vec<usize>,
+    from: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new() -> Self {
+        Nodes {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            cost: Vec::new(),
+            from: Vec::new(),
+        }
+    }
+
+    fn id(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.cost.push(usize::MAX);
+        self.from.push(None);
+        i
+    }
+
+    fn unwind(&self, goal: usize) -> Path<N> {
+        let mut nodes = vec![self.nodes[goal].clone()];
+        let mut here = goal;
+        while let Some(prev) = self.from[here] {
+            nodes.push(self.nodes[prev].clone());
+            here = prev;
+        }
+        nodes.reverse();
+        Path {
+            cost: self.cost[goal],
+            nodes,
+        }
+    }
+}
+
+/// https://en.wikipedia.org/wiki/A*_search_algorithm
+///
+/// `neighbours` yields each next node with the cost of the edge to it, and
+/// `heuristic` must never overestimate the remaining cost or the path found
+/// may not be the cheapest.
+pub fn astar<N, FN, IN, FG, FH>(
+    start: N,
+    mut neighbours: FN,
+    mut goal: FG,
+    mut heuristic: FH,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+    FH: FnMut(&N) -> usize,
+{
+    let mut seen = Nodes::new();
+    let mut open = BinaryHeap::new();
+    let s = seen.id(start);
+    seen.cost[s] = 0;
+    open.push(Reverse((heuristic(&seen.nodes[s]), 0, s)));
+
+    while let Some(Reverse((_, g, current))) = open.pop() {
+        if g > seen.cost[current] {
+            // a cheaper way here was already expanded
+            continue;
+        }
+        if goal(&seen.nodes[current]) {
+            return Some(seen.unwind(current));
+        }
+        let node = seen.nodes[current].clone();
+        for (next, step) in neighbours(&node) {
+            let tentative = g + step;
+            let n = seen.id(next);
+            if tentative < seen.cost[n] {
+                seen.cost[n] = tentative;
+                seen.from[n] = Some(current);
+                let f = tentative + heuristic(&seen.nodes[n]);
+                open.push(Reverse((f, tentative, n)));
+            }
+        }
+    }
+    None
+}
+
+/// A* with nothing to guide it.
+pub fn dijkstra<N, FN, IN, FG>(start: N, neighbours: FN, goal: FG) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, goal, |_| 0)
+}
+
+/// Breadth first, for graphs where every edge costs one.
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut goal: FG) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut seen = Nodes::new();
+    let s = seen.id(start);
+    seen.cost[s] = 0;
+    let mut queue = VecDeque::from([s]);
+    while let Some(current) = queue.pop_front() {
+        if goal(&seen.nodes[current]) {
+            return Some(seen.unwind(current));
+        }
+        let node = seen.nodes[current].clone();
+        for next in neighbours(&node) {
+            let n = seen.id(next);
+            if seen.cost[n] == usize::MAX {
+                seen.cost[n] = seen.cost[current] + 1;
+                seen.from[n] = Some(current);
+                queue.push_back(n);
+            }
+        }
+    }
+    None
+}
+
+/// https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+///
+/// The cheapest cost between every ordered pair of `nodes` that are connected
+/// at all, including each node to itself at no cost.
+pub fn all_pairs<N, FN, IN>(nodes: &[N], mut neighbours: FN) -> HashMap<(N, N), usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, usize)>,
+{
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let n = nodes.len();
+    let mut dist = vec![vec![usize::MAX; n]; n];
+    for (i, node) in nodes.iter().enumerate() {
+        dist[i][i] = 0;
+        for (next, cost) in neighbours(node) {
+            let j = *index.get(&next).expect("edge leads outside the node list");
+            dist[i][j] = dist[i][j].min(cost);
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            if dist[i][k] == usize::MAX {
+                continue;
+            }
+            for j in 0..n {
+                let through = dist[i][k].saturating_add(dist[k][j]);
+                if through < dist[i][j] {
+                    dist[i][j] = through;
+                }
+            }
+        }
+    }
+    let mut routes = HashMap::new();
+    for (i, a) in nodes.iter().enumerate() {
+        for (j, b) in nodes.iter().enumerate() {
+            if dist[i][j] != usize::MAX {
+                routes.insert((a.clone(), b.clone()), dist[i][j]);
+            }
+        }
+    }
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1- b -1- c
+    //  \----5----/   d is on its own
+    fn edges(n: &char) -> Vec<(char, usize)> {
+        match n {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('a', 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', edges, |n| *n == 'c').unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, vec!['a', 'b', 'c']);
+        assert!(dijkstra('a', edges, |n| *n == 'd').is_none());
+        assert_eq!(dijkstra('d', edges, |n| *n == 'd').unwrap().cost, 0);
+    }
+
+    #[test]
+    fn test_astar() {
+        // walk a 10x10 open field from corner to corner
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(1, 0), (0, 1), (-1, 0), (0, -1)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                .map(|p| (p, 1))
+        };
+        let h = |&(x, y): &(i32, i32)| (9 - x + 9 - y) as usize;
+        let path = astar((0, 0), neighbours, |p| *p == (9, 9), h).unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(9, 9)));
+    }
+
+    #[test]
+    fn test_bfs() {
+        let unweighted = |n: &char| edges(n).into_iter().map(|(m, _)| m);
+        let path = bfs('a', unweighted, |n| *n == 'c').unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, vec!['a', 'c']);
+        assert!(bfs('d', unweighted, |n| *n == 'a').is_none());
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let routes = all_pairs(&['a', 'b', 'c', 'd'], edges);
+        assert_eq!(routes[&('a', 'a')], 0);
+        assert_eq!(routes[&('a', 'c')], 2);
+        assert_eq!(routes[&('c', 'a')], 2);
+        assert_eq!(routes[&('d', 'd')], 0);
+        assert!(!routes.contains_key(&('a', 'd')));
+        assert_eq!(routes.len(), 10);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Image, ParseError, Screen};
+use std::fmt::{self, Display};
+
+/// A day of the calendar: parse the input once, then answer both parts from
+/// the parsed form. Input that doesn't parse is reported rather than panicked
+/// over, so the runner can say where it went wrong.
+pub trait Solution: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    fn part1(&self) -> Self::Part1;
+    fn part2(&self) -> Self::Part2;
+
+    /// Draws how `part` gets solved onto `screen`, frame by frame. Says
+    /// whether there was anything to draw.
+    fn visualize(&self, _part: u8, _screen: &mut dyn Screen) -> bool {
+        false
+    }
+
+    /// A picture of the puzzle and both its answers, if the day draws one.
+    fn picture(&self) -> Option<Image> {
+        None
+    }
+}
+
+/// Stand-in answer for a part that hasn't been solved yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum(Vec<u32>);
+
+    impl Solution for Sum {
+        type Part1 = u32;
+        type Part2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            input
+                .lines()
+                .map(|l| l.parse().map_err(|_| ParseError::line("not a number", l)))
+                .collect::<Result<_, _>>()
+                .map(Sum)
+        }
+        fn part1(&self) -> u32 {
+            self.0.iter().sum()
+        }
+        fn part2(&self) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let sum = Sum::parse("1\n2\n3").unwrap();
+        assert_eq!(sum.part1(), 6);
+        assert_eq!(sum.part2().to_string(), "unsolved");
+        assert_eq!(Sum::parse("1\nx").err().unwrap().text, "x");
+        assert!(!sum.visualize(1, &mut Vec::new()));
+        assert!(sum.picture().is_none());
+    }
+}
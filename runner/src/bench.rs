@@ -0,0 +1,139 @@
+//! Timing for `aoc bench`: parse, part 1 and part 2 are each timed on their
+//! own so a slow day shows where its time actually goes.
+
+use aoc::{Day, ParseError};
+use serde::Serialize;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Spread of one phase over every iteration, in nanoseconds so the JSON
+/// report diffs cleanly between commits.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn of(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        Stats {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(median),
+            max_ns: nanos(samples[n - 1]),
+        }
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// A day that couldn't be timed, because its input is missing or doesn't
+/// parse.
+#[derive(Debug, Serialize)]
+pub struct Failure {
+    pub year: u16,
+    pub day: u8,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+    pub failed: Vec<Failure>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+/// Times every phase `iterations` times. Each iteration parses afresh and
+/// then runs both parts on what it parsed.
+pub fn bench_day(
+    year: u16,
+    day: &Day,
+    input: &str,
+    iterations: usize,
+) -> Result<DayReport, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let answers = black_box(day.parse(input)?);
+        parse.push(start.elapsed());
+        part1.push(time(|| answers.part1()));
+        part2.push(time(|| answers.part2()));
+    }
+    Ok(DayReport {
+        year,
+        day: day.number,
+        parse: Stats::of(&mut parse),
+        part1: Stats::of(&mut part1),
+        part2: Stats::of(&mut part2),
+    })
+}
+
+impl DayReport {
+    pub fn print(&self) {
+        for (phase, stats) in [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ] {
+            println!(
+                "{} day {:02} {phase:<6}  min {:>10.3?}  median {:>10.3?}  max {:>10.3?}",
+                self.year,
+                self.day,
+                Duration::from_nanos(stats.min_ns),
+                Duration::from_nanos(stats.median_ns),
+                Duration::from_nanos(stats.max_ns),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&n| Duration::from_millis(n)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::of(&mut ms(&[5, 1, 3]));
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1_000_000,
+                median_ns: 3_000_000,
+                max_ns: 5_000_000
+            }
+        );
+        assert_eq!(Stats::of(&mut ms(&[4, 1, 2, 8])).median_ns, 3_000_000);
+        assert_eq!(Stats::of(&mut ms(&[7])).max_ns, 7_000_000);
+    }
+}
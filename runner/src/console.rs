@@ -0,0 +1,180 @@
+//! `aoc shell` and `aoc debug`: something to look around in a command at a
+//! time, typed in or read from a script. The shell is the filesystem rebuilt
+//! from the 2022 day 7 terminal log, and the debugger steps through the 2022
+//! day 10 program.
+
+use advent2022::Shell;
+use aoc::cpu::Peripheral;
+use aoc::debugger::Debugger;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::Path;
+
+/// Something that takes commands a line at a time.
+pub trait Console {
+    fn prompt(&self) -> String;
+
+    /// Runs one command line, and says what it printed or what was wrong.
+    fn run(&mut self, line: &str) -> Result<String, String>;
+}
+
+impl Console for Shell {
+    fn prompt(&self) -> String {
+        Shell::prompt(self)
+    }
+
+    fn run(&mut self, line: &str) -> Result<String, String> {
+        Shell::run(self, line)
+    }
+}
+
+impl<P: Peripheral> Console for Debugger<P> {
+    fn prompt(&self) -> String {
+        Debugger::prompt(self)
+    }
+
+    fn run(&mut self, line: &str) -> Result<String, String> {
+        Debugger::run(self, line)
+    }
+}
+
+/// Runs each line of `commands` on `console`, with what a command prints
+/// going to `out` and what went wrong to `err`. Typed commands get the
+/// prompt before them, while a script's are echoed after it, so the output
+/// reads like a log. Blank lines and `#` comments are skipped, and `exit`
+/// stops early. Says how many commands failed.
+pub fn session<C: Console + ?Sized, R: BufRead, W: Write, E: Write>(
+    console: &mut C,
+    commands: R,
+    mut out: W,
+    mut err: E,
+    interactive: bool,
+) -> io::Result<usize> {
+    let mut failed = 0;
+    let mut lines = commands.lines();
+    loop {
+        if interactive {
+            write!(out, "{}", console.prompt())?;
+            out.flush()?;
+        }
+        let Some(line) = lines.next() else {
+            if interactive {
+                writeln!(out)?;
+            }
+            break;
+        };
+        let command = line?;
+        let command = command.trim();
+        if command.is_empty() || command.starts_with('#') {
+            continue;
+        }
+        if !interactive {
+            writeln!(out, "{}{command}", console.prompt())?;
+        }
+        if command == "exit" {
+            break;
+        }
+        match console.run(command) {
+            Ok(printed) => write!(out, "{printed}")?,
+            Err(e) => {
+                out.flush()?;
+                writeln!(err, "{e}")?;
+                failed += 1;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(failed)
+}
+
+/// Runs a script on `console`, or whatever is typed at it. Only a script's
+/// failures make the whole thing fail, since typing mistakes are part of
+/// exploring.
+pub fn attend<C: Console + ?Sized>(console: &mut C, script: Option<&Path>) -> Result<(), String> {
+    let failed = match script {
+        Some(script) => {
+            let commands = File::open(script)
+                .map_err(|e| format!("could not read {}: {e}", script.display()))?;
+            let commands = BufReader::new(commands);
+            session(console, commands, io::stdout().lock(), io::stderr(), false)
+        }
+        None => {
+            let interactive = io::stdin().is_terminal();
+            let commands = io::stdin().lock();
+            let failed = session(
+                console,
+                commands,
+                io::stdout().lock(),
+                io::stderr(),
+                interactive,
+            );
+            failed.map(|n| if interactive { 0 } else { n })
+        }
+    }
+    .map_err(|e| e.to_string())?;
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} commands failed")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "$ cd /\n$ ls\ndir a\n10 b.txt\n$ cd a\n$ ls\n200 c\n";
+
+    #[test]
+    fn test_script() {
+        let mut shell = Shell::new(LOG).unwrap();
+        let script = "# look around\ndu -s\n\ncd a\nls\nrm nothing\nrm c\ndu -s /\nexit\nls\n";
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let failed = session(&mut shell, script.as_bytes(), &mut out, &mut err, false).unwrap();
+        assert_eq!(failed, 1);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "/ $ du -s\n210\t/\n/ $ cd a\n/a $ ls\n200 c\n/a $ rm nothing\n\
+             /a $ rm c\n/a $ du -s /\n10\t/\n/a $ exit\n"
+        );
+        assert_eq!(
+            String::from_utf8(err).unwrap(),
+            "nothing: no such file or directory\n"
+        );
+    }
+
+    #[test]
+    fn test_interactive() {
+        let mut shell = Shell::new(LOG).unwrap();
+        let mut out = Vec::new();
+        let failed = session(
+            &mut shell,
+            "cd a\npwd\n".as_bytes(),
+            &mut out,
+            io::sink(),
+            true,
+        );
+        assert_eq!(failed.unwrap(), 0);
+        assert_eq!(String::from_utf8(out).unwrap(), "/ $ /a $ /a\n/a $ \n");
+    }
+
+    #[test]
+    fn test_debugger() {
+        let mut debugger = advent2022::debugger("noop\naddx 3\naddx -5").unwrap();
+        let script = "b pc 2\nc\nregs\nc\n";
+        let mut out = Vec::new();
+        let failed = session(
+            &mut debugger,
+            script.as_bytes(),
+            &mut out,
+            io::sink(),
+            false,
+        );
+        assert_eq!(failed.unwrap(), 0);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "(cycle 0) b pc 2\nbreakpoint at pc 2\n(cycle 0) c\nstopped at pc 2\n\
+             before cycle 4: x=4, pc 2: addx -5 [0/2]\n(cycle 3) regs\nx=4\n\
+             (cycle 3) c\nhalted after cycle 5: x=-1\n"
+        );
+    }
+}
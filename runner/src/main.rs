@@ -0,0 +1,614 @@
+//! `aoc`: one binary that runs any registered day of any year.
+
+mod bench;
+mod console;
+mod scaffold;
+mod visualize;
+
+use aoc::{Answers, Calendar, Day, Expected, InputCache, Screen, Verdict};
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::{fs, process};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs advent of code solutions",
+    after_help = "Inputs are read from each year's input directory, or from \
+                  $AOC_INPUT_DIR/<year>/<ddd>.txt, like 2023/004.txt, when \
+                  AOC_INPUT_DIR is set. Without -v, AOC_LOG=debug or \
+                  AOC_LOG=trace turns on the solutions' diagnostics instead."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show the solutions' diagnostics on stderr, -vv for every step
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day of a year with --all
+    Run(RunArgs),
+    /// Check answers against each year's answers.toml
+    Check(CheckArgs),
+    /// Time parse, part 1 and part 2 of every selected day
+    Bench(BenchArgs),
+    /// Scaffold a new day in a year's crate
+    New(NewArgs),
+    /// Play back a frame log written by `run --record`
+    Replay(ReplayArgs),
+    /// Explore the 2022 day 7 filesystem with cd, ls, du, find and rm
+    Shell(ConsoleArgs),
+    /// Step through the 2022 day 10 program, with breakpoints
+    Debug(ConsoleArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    year: u16,
+    /// Day of the month, e.g. 17
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from here instead of the year's input directory
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Run every registered day of the year
+    #[arg(long)]
+    all: bool,
+    /// Animate the day's simulation in the terminal before answering
+    #[arg(long, conflicts_with = "all")]
+    visualize: bool,
+    /// Write the animation here: an asciicast if it ends in .cast, otherwise
+    /// a plain text frame log
+    #[arg(long, conflicts_with = "all")]
+    record: Option<PathBuf>,
+    /// Frames per second to animate and record at
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    fps: u32,
+    /// Only show every Nth frame
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+    /// Save a picture of the day here: a PNG if it ends in .png (with the png
+    /// feature), otherwise a PPM
+    #[arg(long, conflicts_with = "all")]
+    image: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Only check this year
+    year: Option<u16>,
+    /// Only check this day of the year
+    #[arg(requires = "year")]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only bench this year
+    year: Option<u16>,
+    /// Only bench this day of the year
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// How many times to run each phase
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+    /// Where to write the JSON report
+    #[arg(long, default_value = "bench.json")]
+    json: PathBuf,
+}
+
+#[derive(Args)]
+struct ReplayArgs {
+    /// The frame log to play
+    log: PathBuf,
+    /// Frames per second to play at
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    fps: u32,
+}
+
+#[derive(Args)]
+struct ConsoleArgs {
+    /// Run the commands in this file instead of reading them from stdin
+    script: Option<PathBuf>,
+    /// Read the puzzle input from here instead of the day's own
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+    /// Day of the month, e.g. 12
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn calendars() -> Vec<Calendar> {
+    vec![advent2022::calendar(), advent2023::calendar()]
+}
+
+fn find_year(calendars: &[Calendar], year: u16) -> Result<&Calendar, String> {
+    calendars
+        .iter()
+        .find(|c| c.year == year)
+        .ok_or_else(|| format!("no solutions registered for {year}"))
+}
+
+fn find_day(calendar: &Calendar, day: u8) -> Result<&Day, String> {
+    calendar
+        .day(day)
+        .ok_or_else(|| format!("no solution registered for {} day {day}", calendar.year))
+}
+
+fn select_years(calendars: &[Calendar], year: Option<u16>) -> Result<Vec<&Calendar>, String> {
+    match year {
+        Some(year) => Ok(vec![find_year(calendars, year)?]),
+        None => Ok(calendars.iter().collect()),
+    }
+}
+
+fn select_days(calendar: &Calendar, day: Option<u8>) -> Result<Vec<&Day>, String> {
+    match day {
+        Some(number) => Ok(vec![find_day(calendar, number)?]),
+        None => Ok(calendar.days.iter().collect()),
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    }
+}
+
+fn answer(answers: &dyn Answers, part: u8) -> String {
+    match part {
+        1 => answers.part1(),
+        _ => answers.part2(),
+    }
+}
+
+fn run_day(
+    calendar: &Calendar,
+    day: &Day,
+    path: &Path,
+    input: &str,
+    part: Option<u8>,
+) -> Result<(), String> {
+    let answers = day
+        .parse(input)
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    for p in parts(part) {
+        println!(
+            "{} day {:02} part {p}: {}",
+            calendar.year,
+            day.number,
+            answer(answers.as_ref(), p)
+        );
+    }
+    Ok(())
+}
+
+/// Draws the selected parts of a day on the terminal and any recording.
+fn visualize_day(
+    calendar: &Calendar,
+    day: &Day,
+    path: &Path,
+    input: &str,
+    args: &RunArgs,
+) -> Result<(), String> {
+    let answers = day
+        .parse(input)
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    let mut projector = visualize::Projector::new(args.every as usize);
+    if args.visualize {
+        projector = projector.terminal(args.fps);
+    }
+    if let Some(record) = &args.record {
+        projector = projector.record(record, args.fps)?;
+    }
+    let mut drawn = false;
+    for p in parts(args.part) {
+        drawn |= answers.visualize(p, &mut projector);
+    }
+    projector.finish()?;
+    match drawn {
+        true => Ok(()),
+        false => Err(format!(
+            "{} day {:02} has nothing to visualize",
+            calendar.year, day.number
+        )),
+    }
+}
+
+/// Saves the day's picture to `image`.
+fn picture_day(
+    calendar: &Calendar,
+    day: &Day,
+    path: &Path,
+    input: &str,
+    image: &Path,
+) -> Result<(), String> {
+    let answers = day
+        .parse(input)
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    let picture = answers.picture().ok_or_else(|| {
+        format!(
+            "{} day {:02} has nothing to picture",
+            calendar.year, day.number
+        )
+    })?;
+    picture
+        .save(image)
+        .map_err(|e| format!("could not save {}: {e}", image.display()))?;
+    println!("wrote {}", image.display());
+    Ok(())
+}
+
+/// The cached input for a day, and where it was found for error messages.
+fn cached_input(
+    inputs: &InputCache,
+    calendar: &Calendar,
+    day: &Day,
+) -> Result<(PathBuf, String), String> {
+    let path = inputs.path(calendar, day.number);
+    let input = inputs
+        .load(calendar, day.number)
+        .map_err(|e| e.to_string())?;
+    Ok((path, input))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let calendars = calendars();
+    let inputs = InputCache::from_env();
+    let calendar = find_year(&calendars, args.year)?;
+    if args.all {
+        let mut failed = 0;
+        for day in calendar.days.iter() {
+            let result = cached_input(&inputs, calendar, day)
+                .and_then(|(path, input)| run_day(calendar, day, &path, &input, args.part));
+            if let Err(e) = result {
+                eprintln!("{e}");
+                failed += 1;
+            }
+        }
+        return match failed {
+            0 => Ok(()),
+            n => Err(format!("{n} days could not be run")),
+        };
+    }
+    let number = args.day.expect("clap requires a day without --all");
+    let day = find_day(calendar, number)?;
+    let (path, input) = match &args.input {
+        Some(path) => (path.clone(), aoc::load(path).map_err(|e| e.to_string())?),
+        None => cached_input(&inputs, calendar, day)?,
+    };
+    if args.visualize || args.record.is_some() {
+        visualize_day(calendar, day, &path, &input, args)?;
+    }
+    if let Some(image) = &args.image {
+        picture_day(calendar, day, &path, &input, image)?;
+    }
+    run_day(calendar, day, &path, &input, args.part)
+}
+
+/// Running totals for `check`, counted per part.
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unrecorded: usize,
+}
+
+fn check_day(
+    inputs: &InputCache,
+    calendar: &Calendar,
+    expected: &Expected,
+    day: &Day,
+    tally: &mut Tally,
+) -> Result<(), String> {
+    let (path, input) = cached_input(inputs, calendar, day)?;
+    let answers = day
+        .parse(&input)
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    for p in parts(None) {
+        let got = answer(answers.as_ref(), p);
+        let label = format!("{} day {:02} part {p}", calendar.year, day.number);
+        match Verdict::of(expected.get(day.number, p), &got) {
+            Verdict::Pass => {
+                println!("{label}: ok");
+                tally.passed += 1;
+            }
+            Verdict::Fail { expected } => {
+                println!("{label}: MISMATCH, expected {expected} but got {got}");
+                tally.failed += 1;
+            }
+            Verdict::Unrecorded => {
+                println!("{label}: no recorded answer, got {got}");
+                tally.unrecorded += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check(args: &CheckArgs) -> Result<(), String> {
+    let calendars = calendars();
+    let inputs = InputCache::from_env();
+    let mut tally = Tally::default();
+    let mut broken = 0;
+    for calendar in select_years(&calendars, args.year)? {
+        let expected = Expected::load(calendar.answers_path()).map_err(|e| e.to_string())?;
+        for day in select_days(calendar, args.day)? {
+            if let Err(e) = check_day(&inputs, calendar, &expected, day, &mut tally) {
+                eprintln!("{e}");
+                broken += 1;
+            }
+        }
+    }
+    println!(
+        "{} passed, {} failed, {} unrecorded",
+        tally.passed, tally.failed, tally.unrecorded
+    );
+    match (tally.failed, broken) {
+        (0, 0) => Ok(()),
+        (0, n) => Err(format!("{n} days could not be run")),
+        (n, _) => Err(format!("{n} answers no longer match")),
+    }
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let calendars = calendars();
+    let inputs = InputCache::from_env();
+    let iterations = args.iterations as usize;
+    let mut report = bench::Report {
+        iterations,
+        days: Vec::new(),
+        failed: Vec::new(),
+    };
+    for calendar in select_years(&calendars, args.year)? {
+        for day in select_days(calendar, args.day)? {
+            // one day that can't be run still leaves the others worth timing
+            let timings = cached_input(&inputs, calendar, day).and_then(|(path, input)| {
+                bench::bench_day(calendar.year, day, &input, iterations)
+                    .map_err(|e| format!("{}: {e}", path.display()))
+            });
+            match timings {
+                Ok(timings) => {
+                    timings.print();
+                    report.days.push(timings);
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    report.failed.push(bench::Failure {
+                        year: calendar.year,
+                        day: day.number,
+                        error,
+                    });
+                }
+            }
+        }
+    }
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    fs::write(&args.json, json + "\n")
+        .map_err(|e| format!("could not write {}: {e}", args.json.display()))?;
+    println!("wrote {}", args.json.display());
+    match report.failed.len() {
+        0 => Ok(()),
+        n => Err(format!("{n} days could not be run")),
+    }
+}
+
+fn new(args: &NewArgs) -> Result<(), String> {
+    let calendars = calendars();
+    let calendar = find_year(&calendars, args.year)?;
+    for path in scaffold::new_day(calendar, args.day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn replay(args: &ReplayArgs) -> Result<(), String> {
+    let log = fs::read_to_string(&args.log)
+        .map_err(|e| format!("could not read {}: {e}", args.log.display()))?;
+    let frames = visualize::read_frames(&log);
+    if frames.is_empty() {
+        return Err(format!("{} has no frames in it", args.log.display()));
+    }
+    let mut projector = visualize::Projector::new(1).terminal(args.fps);
+    for frame in frames {
+        if !projector.show(&frame) {
+            break;
+        }
+    }
+    projector.finish()
+}
+
+/// The 2022 input for `day`, or the one given instead.
+fn input_2022(day: u8, input: Option<&Path>) -> Result<(PathBuf, String), String> {
+    match input {
+        Some(path) => Ok((path.to_owned(), aoc::load(path).map_err(|e| e.to_string())?)),
+        None => {
+            let calendars = calendars();
+            let calendar = find_year(&calendars, 2022)?;
+            cached_input(&InputCache::from_env(), calendar, find_day(calendar, day)?)
+        }
+    }
+}
+
+fn explore(args: &ConsoleArgs) -> Result<(), String> {
+    let (path, log) = input_2022(7, args.input.as_deref())?;
+    let mut shell = advent2022::Shell::new(&log).map_err(|e| format!("{}: {e}", path.display()))?;
+    console::attend(&mut shell, args.script.as_deref())
+}
+
+fn debug(args: &ConsoleArgs) -> Result<(), String> {
+    let (path, program) = input_2022(10, args.input.as_deref())?;
+    let mut debugger =
+        advent2022::debugger(&program).map_err(|e| format!("{}: {e}", path.display()))?;
+    console::attend(&mut debugger, args.script.as_deref())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if cli.verbose > 0 {
+        aoc::log::set_level(aoc::log::Level::from_verbosity(cli.verbose));
+    }
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Check(args) => check(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+        Command::Replay(args) => replay(args),
+        Command::Shell(args) => explore(args),
+        Command::Debug(args) => debug(args),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_run_args() {
+        let cli = Cli::try_parse_from(["aoc", "run", "2022", "17", "--part", "2"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!((args.year, args.day, args.part), (2022, Some(17), Some(2)));
+        assert_eq!(cli.verbose, 0);
+        let cli = Cli::try_parse_from(["aoc", "run", "2022", "21", "-vv"]).unwrap();
+        assert_eq!(cli.verbose, 2);
+        assert!(Cli::try_parse_from(["aoc", "run", "2022"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "2022", "17", "--all"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "2022", "17", "--part", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "2023", "--all"]).is_ok());
+    }
+
+    #[test]
+    fn test_visualize_args() {
+        let cli =
+            Cli::try_parse_from(["aoc", "run", "2022", "14", "--visualize", "--fps", "5"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert!(args.visualize);
+        assert_eq!((args.fps, args.every, args.record), (5, 1, None));
+        let cli =
+            Cli::try_parse_from(["aoc", "run", "2022", "23", "--record", "elves.cast"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!(args.record, Some(PathBuf::from("elves.cast")));
+        assert!(Cli::try_parse_from(["aoc", "run", "2022", "--all", "--visualize"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "2022", "14", "--every", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "replay", "sand.txt", "--fps", "60"]).is_ok());
+        let cli = Cli::try_parse_from(["aoc", "run", "2022", "18", "--image", "lava.ppm"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!(args.image, Some(PathBuf::from("lava.ppm")));
+        assert!(Cli::try_parse_from(["aoc", "run", "2022", "--all", "--image", "x.ppm"]).is_err());
+    }
+
+    #[test]
+    fn test_registered() {
+        let calendars = calendars();
+        assert_eq!(find_year(&calendars, 2022).unwrap().days.len(), 25);
+        assert!(find_year(&calendars, 2021).is_err());
+        let c2023 = find_year(&calendars, 2023).unwrap();
+        assert!(find_day(c2023, 11).is_ok());
+        assert!(find_day(c2023, 25).is_err());
+    }
+
+    #[test]
+    fn test_check_args() {
+        assert!(Cli::try_parse_from(["aoc", "check"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "check", "2022", "17"]).is_ok());
+        let cli = Cli::try_parse_from(["aoc", "check", "2023"]).unwrap();
+        let Command::Check(args) = cli.command else {
+            panic!("expected check");
+        };
+        assert_eq!((args.year, args.day), (Some(2023), None));
+    }
+
+    #[test]
+    fn test_bench_args() {
+        let cli = Cli::try_parse_from(["aoc", "bench", "2022", "16", "-n", "3"]).unwrap();
+        let Command::Bench(args) = cli.command else {
+            panic!("expected bench");
+        };
+        assert_eq!(
+            (args.year, args.day, args.iterations),
+            (Some(2022), Some(16), 3)
+        );
+        assert_eq!(args.json, PathBuf::from("bench.json"));
+        assert!(Cli::try_parse_from(["aoc", "bench", "-n", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_shell_args() {
+        let cli = Cli::try_parse_from(["aoc", "shell"]).unwrap();
+        let Command::Shell(args) = cli.command else {
+            panic!("expected shell");
+        };
+        assert_eq!((args.script, args.input), (None, None));
+        let cli =
+            Cli::try_parse_from(["aoc", "shell", "cleanup.sh", "--input", "log.txt"]).unwrap();
+        let Command::Shell(args) = cli.command else {
+            panic!("expected shell");
+        };
+        assert_eq!(args.script, Some(PathBuf::from("cleanup.sh")));
+        assert_eq!(args.input, Some(PathBuf::from("log.txt")));
+        assert!(Cli::try_parse_from(["aoc", "shell", "a.sh", "b.sh"]).is_err());
+        let cli = Cli::try_parse_from(["aoc", "debug", "--input", "program.txt"]).unwrap();
+        let Command::Debug(args) = cli.command else {
+            panic!("expected debug");
+        };
+        assert_eq!(
+            (args.script, args.input),
+            (None, Some(PathBuf::from("program.txt")))
+        );
+    }
+
+    #[test]
+    fn test_new_args() {
+        let cli = Cli::try_parse_from(["aoc", "new", "2023", "12"]).unwrap();
+        let Command::New(args) = cli.command else {
+            panic!("expected new");
+        };
+        assert_eq!((args.year, args.day), (2023, 12));
+        assert!(Cli::try_parse_from(["aoc", "new", "2023"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "new", "2023", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_recorded_days_are_registered() {
+        for calendar in calendars() {
+            let expected = Expected::load(calendar.answers_path()).unwrap();
+            for number in expected.days() {
+                assert!(
+                    find_day(&calendar, number).is_ok(),
+                    "{} day {number}",
+                    calendar.year
+                );
+            }
+        }
+    }
+}
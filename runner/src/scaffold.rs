@@ -0,0 +1,324 @@
+//! `aoc new`: starts a day in a year's crate. The module comes from
+//! `template.rs` with an empty sample fixture and a sidecar waiting for the
+//! sample's answers (see `aoc::sample`), the day is registered in the year's
+//! `lib.rs`, and the year's vscode launch config gains entries for running
+//! and testing it.
+
+use aoc::Calendar;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../template.rs");
+
+const SIDECAR: &str = "# the answers the puzzle gives for DAY-sample-1.txt
+# part1 = \"\"
+# part2 = \"\"
+";
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+/// A file `new_day` writes, with what was there before: `None` when it is
+/// new, so undoing the write removes it.
+struct Change {
+    path: PathBuf,
+    contents: String,
+    original: Option<String>,
+}
+
+/// Makes every change in turn, and if one of them fails puts back the ones
+/// already made before saying why.
+fn apply(changes: Vec<Change>) -> Result<Vec<PathBuf>, String> {
+    for (i, change) in changes.iter().enumerate() {
+        if let Err(e) = write(&change.path, &change.contents) {
+            for done in changes[..i].iter().rev() {
+                let _ = match &done.original {
+                    Some(original) => fs::write(&done.path, original),
+                    None => fs::remove_file(&done.path),
+                };
+            }
+            return Err(e);
+        }
+    }
+    Ok(changes.into_iter().map(|change| change.path).collect())
+}
+
+/// Puts `entry` among the lines `is_entry` picks out, keeping them sorted.
+/// Those lines have to already be there, since they are what says where in
+/// the file the entry belongs.
+fn insert_sorted<F: Fn(&str) -> bool>(
+    text: &str,
+    is_entry: F,
+    entry: &str,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let last = *entries
+        .last()
+        .ok_or_else(|| format!("found nowhere to add `{}`", entry.trim()))?;
+    let at = entries
+        .into_iter()
+        .find(|&i| lines[i] > entry)
+        .unwrap_or(last + 1);
+    lines.insert(at, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day's module and its line in `calendar()` to a year's `lib.rs`.
+fn register(lib: &str, day: &str, number: u8) -> Result<String, String> {
+    let lib = insert_sorted(lib, |l| l.starts_with("mod day"), &format!("mod day{day};"))?;
+    insert_sorted(
+        &lib,
+        |l| l.trim_start().starts_with("Day::new::<"),
+        &format!("            Day::new::<day{day}::Day{day}>({number}),"),
+    )
+}
+
+fn launch_entries(year: u16, day: &str, number: u8) -> String {
+    format!(
+        r#"        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug day '{day}'",
+            "cargo": {{
+                "args": [
+                    "build",
+                    "--bin=aoc",
+                    "--package=aoc-runner"
+                ],
+                "filter": {{
+                    "name": "aoc",
+                    "kind": "bin"
+                }}
+            }},
+            "args": [
+                "run",
+                "{year}",
+                "{number}"
+            ],
+            "cwd": "${{workspaceFolder}}"
+        }},
+        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug unit tests in day '{day}'",
+            "cargo": {{
+                "args": [
+                    "test",
+                    "--no-run",
+                    "--lib",
+                    "--package=advent{year}"
+                ],
+                "filter": {{
+                    "name": "advent{year}",
+                    "kind": "lib"
+                }}
+            }},
+            "args": [
+                "day{day}::"
+            ],
+            "cwd": "${{workspaceFolder}}"
+        }}"#
+    )
+}
+
+/// Appends the day's configurations to a launch.json, unless they are
+/// already there. The file has comments in it, so it is edited as text: the
+/// entries go just before the `]` closing `configurations`.
+fn add_launch(json: &str, year: u16, day: &str, number: u8) -> Result<String, String> {
+    if json.contains(&format!("\"name\": \"Debug day '{day}'\"")) {
+        return Ok(json.to_string());
+    }
+    let close = json
+        .rfind(']')
+        .ok_or("launch.json has no configurations to add to")?;
+    let head = json[..close].trim_end();
+    let separator = if head.ends_with('}') { "," } else { "" };
+    Ok(format!(
+        "{head}{separator}\n{}\n    {}",
+        launch_entries(year, day, number),
+        &json[close..]
+    ))
+}
+
+/// Scaffolds `number` in the year's crate and returns the files it touched.
+/// Everything is worked out before anything is written, a write that fails
+/// undoes the ones before it, and an existing day is never overwritten.
+pub fn new_day(calendar: &Calendar, number: u8) -> Result<Vec<PathBuf>, String> {
+    let day = format!("{number:03}");
+    let module = calendar.root.join("src").join(format!("day{day}.rs"));
+    if calendar.day(number).is_some() || module.exists() {
+        return Err(format!(
+            "{} day {number:02} already exists, not overwriting it",
+            calendar.year
+        ));
+    }
+    let mut changes = vec![Change {
+        path: module,
+        contents: TEMPLATE.replace("DAY", &day),
+        original: None,
+    }];
+    let input = calendar.root.join("input");
+    let sample = input.join(format!("{day}-sample-1.txt"));
+    let sidecar = sample.with_extension("toml");
+    for (path, contents) in [
+        (sample, String::new()),
+        (sidecar, SIDECAR.replace("DAY", &day)),
+    ] {
+        if !path.exists() {
+            changes.push(Change {
+                path,
+                contents,
+                original: None,
+            });
+        }
+    }
+    let lib_path = calendar.root.join("src").join("lib.rs");
+    let lib = read(&lib_path)?;
+    changes.push(Change {
+        contents: register(&lib, &day, number)?,
+        path: lib_path,
+        original: Some(lib),
+    });
+    let launch_path = calendar.root.join(".vscode").join("launch.json");
+    if launch_path.exists() {
+        let launch = read(&launch_path)?;
+        changes.push(Change {
+            contents: add_launch(&launch, calendar.year, &day, number)?,
+            path: launch_path,
+            original: Some(launch),
+        });
+    }
+
+    fs::create_dir_all(&input).map_err(|e| format!("could not create {}: {e}", input.display()))?;
+    apply(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Day, ParseError, Solution, Unsolved};
+
+    const LIB: &str = r#"use aoc::{Calendar, Day};
+
+mod day001;
+mod day003;
+
+pub fn calendar() -> Calendar {
+    Calendar::new(
+        2023,
+        env!("CARGO_MANIFEST_DIR"),
+        vec![
+            Day::new::<day001::Day001>(1),
+            Day::new::<day003::Day003>(3),
+        ],
+    )
+}
+"#;
+
+    const LAUNCH: &str = r#"{
+    // comments are allowed
+    "version": "0.2.0",
+    "configurations": [
+        {
+            "name": "Debug every day of 2023"
+        }
+    ]
+}
+"#;
+
+    struct Blank;
+
+    impl Solution for Blank {
+        type Part1 = Unsolved;
+        type Part2 = Unsolved;
+
+        fn parse(_: &str) -> Result<Self, ParseError> {
+            Ok(Blank)
+        }
+        fn part1(&self) -> Unsolved {
+            Unsolved
+        }
+        fn part2(&self) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let lib = register(LIB, "002", 2).unwrap();
+        assert!(lib.contains("mod day001;\nmod day002;\nmod day003;\n"));
+        assert!(lib.contains(
+            "(1),\n            Day::new::<day002::Day002>(2),\n            Day::new::<day003::"
+        ));
+        let lib = register(&lib, "012", 12).unwrap();
+        assert!(lib.contains("mod day003;\nmod day012;\n\npub fn"));
+        assert!(lib.contains("Day::new::<day012::Day012>(12),\n        ],"));
+        assert!(register("pub fn calendar() {}", "001", 1).is_err());
+    }
+
+    #[test]
+    fn test_add_launch() {
+        let once = add_launch(LAUNCH, 2023, "012", 12).unwrap();
+        assert!(once.contains("2023\"\n        },\n        {\n            \"type\": \"lldb\""));
+        assert!(once.contains("\"name\": \"Debug day '012'\""));
+        assert!(once.contains("\"day012::\""));
+        assert!(once.ends_with("        }\n    ]\n}\n"));
+        assert_eq!(add_launch(&once, 2023, "012", 12).unwrap(), once);
+        let empty = add_launch("{\n    \"configurations\": [\n    ]\n}\n", 2023, "001", 1).unwrap();
+        assert!(empty.contains("[\n        {"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join(".vscode")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join(".vscode/launch.json"), LAUNCH).unwrap();
+        let calendar = Calendar::new(2023, &root, vec![Day::new::<Blank>(1)]);
+
+        let touched = new_day(&calendar, 2).unwrap();
+        assert_eq!(touched.len(), 5);
+        let module = fs::read_to_string(root.join("src/day002.rs")).unwrap();
+        assert!(module.contains("pub struct Day002 {"));
+        assert!(module.contains("include_str!(\"../input/002-sample-1.txt\")"));
+        assert!(!module.contains("DAY"));
+        assert!(root.join("input/002-sample-1.txt").exists());
+        let sidecar = fs::read_to_string(root.join("input/002-sample-1.toml")).unwrap();
+        assert!(sidecar.starts_with("# the answers the puzzle gives for 002-sample-1.txt"));
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("mod day002;"));
+
+        assert!(new_day(&calendar, 1)
+            .unwrap_err()
+            .contains("already exists"));
+        assert!(new_day(&calendar, 2)
+            .unwrap_err()
+            .contains("already exists"));
+        assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), lib);
+
+        // a sidecar that can't be written, after the module and sample have been
+        #[cfg(unix)]
+        {
+            let sidecar = root.join("input/004-sample-1.toml");
+            std::os::unix::fs::symlink(root.join("nowhere/at/all"), &sidecar).unwrap();
+            assert!(new_day(&calendar, 4)
+                .unwrap_err()
+                .contains("could not write"));
+            assert!(!root.join("src/day004.rs").exists());
+            assert!(!root.join("input/004-sample-1.txt").exists());
+            assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), lib);
+            fs::remove_file(sidecar).unwrap();
+            assert_eq!(new_day(&calendar, 4).unwrap().len(), 5);
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,383 @@
+//! Where the frames go when a day is run with `--visualize` or `--record`:
+//! redrawn in place in the terminal at a steady frame rate, written to an
+//! asciicast for `asciinema play`, or written to a plain text frame log for
+//! `aoc replay`.
+
+use aoc::Screen;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How each frame of a frame log starts, followed by its number.
+const FRAME_MARK: &str = "--- frame ";
+
+/// Clears the terminal and puts the cursor top left.
+const CLEAR: &str = "\x1b[H\x1b[J";
+
+/// Somewhere frames end up.
+trait Sink {
+    /// Takes the next frame, and says whether to keep going.
+    fn take(&mut self, frame: &str) -> io::Result<bool>;
+
+    /// Tidies up after the last frame.
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+/// What can be typed at an animation in the terminal, a line at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Control {
+    Pause,
+    Step,
+    Quit,
+}
+
+fn control(line: &str) -> Option<Control> {
+    match line.trim() {
+        "p" => Some(Control::Pause),
+        "" | "n" => Some(Control::Step),
+        "q" => Some(Control::Quit),
+        _ => None,
+    }
+}
+
+const HELP: &str = "p: pause/resume, enter: step, q: quit";
+
+/// Redraws each frame over the last, no faster than the frame rate. Typing
+/// `p` pauses and resumes, enter on its own pauses or moves on a frame while
+/// paused, and `q` stops the animation.
+struct Terminal {
+    frame_time: Duration,
+    due: Instant,
+    paused: bool,
+    shown: usize,
+    controls: Option<Receiver<Control>>,
+}
+
+impl Terminal {
+    fn new(fps: u32) -> Self {
+        // with nobody at the keyboard there is nothing to listen for
+        let controls = io::stdin().is_terminal().then(|| {
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                for line in io::stdin().lock().lines().map_while(Result::ok) {
+                    if let Some(c) = control(&line) {
+                        if tx.send(c).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+            rx
+        });
+        Terminal {
+            frame_time: Duration::from_secs(1) / fps,
+            due: Instant::now(),
+            paused: false,
+            shown: 0,
+            controls,
+        }
+    }
+
+    fn status(&self, out: &mut impl Write) -> io::Result<()> {
+        let state = if self.paused { "paused" } else { "playing" };
+        write!(out, "\r\x1b[Kframe {} ({state}) {HELP}", self.shown)?;
+        out.flush()
+    }
+
+    /// Waits for whoever is watching to let the animation move on, and says
+    /// whether they still want it to.
+    fn wait(&mut self) -> io::Result<bool> {
+        loop {
+            let Some(controls) = &self.controls else {
+                self.pace();
+                return Ok(true);
+            };
+            let next = match self.paused {
+                true => controls.recv().map_err(|_| TryRecvError::Disconnected),
+                false => controls.try_recv(),
+            };
+            match next {
+                Ok(Control::Pause) => self.paused = !self.paused,
+                Ok(Control::Step) if self.paused => return Ok(true),
+                Ok(Control::Step) => self.paused = true,
+                Ok(Control::Quit) => return Ok(false),
+                Err(TryRecvError::Empty) => {
+                    self.pace();
+                    return Ok(true);
+                }
+                // stdin has gone away, so there's nobody left to resume
+                Err(TryRecvError::Disconnected) => {
+                    self.controls = None;
+                    self.paused = false;
+                }
+            }
+            self.status(&mut io::stdout())?;
+        }
+    }
+
+    /// Sleeps until the next frame is due.
+    fn pace(&mut self) {
+        let now = Instant::now();
+        if self.due > now {
+            thread::sleep(self.due - now);
+        }
+        self.due = self.due.max(now) + self.frame_time;
+    }
+}
+
+impl Sink for Terminal {
+    fn take(&mut self, frame: &str) -> io::Result<bool> {
+        self.shown += 1;
+        let mut out = io::stdout().lock();
+        write!(out, "{CLEAR}{frame}")?;
+        if !frame.ends_with('\n') {
+            writeln!(out)?;
+        }
+        self.status(&mut out)?;
+        drop(out);
+        self.wait()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let mut out = io::stdout();
+        writeln!(out)?;
+        out.flush()
+    }
+}
+
+/// An asciicast v2 recording, one event per frame at the frame rate. The
+/// header needs the size of the biggest frame, which isn't known until the
+/// end, so the events go to a `.part` file alongside until then.
+struct Asciicast {
+    path: PathBuf,
+    part: PathBuf,
+    events: BufWriter<File>,
+    frame_time: f64,
+    shown: usize,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Serialize)]
+struct Header {
+    version: u8,
+    width: usize,
+    height: usize,
+}
+
+impl Asciicast {
+    fn create(path: &Path, fps: u32) -> io::Result<Self> {
+        let mut part = path.as_os_str().to_owned();
+        part.push(".part");
+        let part = PathBuf::from(part);
+        Ok(Asciicast {
+            path: path.to_owned(),
+            events: BufWriter::new(File::create(&part)?),
+            part,
+            frame_time: 1.0 / fps as f64,
+            shown: 0,
+            width: 1,
+            height: 1,
+        })
+    }
+}
+
+impl Sink for Asciicast {
+    fn take(&mut self, frame: &str) -> io::Result<bool> {
+        let lines = frame.lines();
+        self.width = self
+            .width
+            .max(lines.clone().map(|l| l.chars().count()).max().unwrap_or(0));
+        self.height = self.height.max(lines.count());
+        let time = self.shown as f64 * self.frame_time;
+        let output = format!("{CLEAR}{}", frame.replace('\n', "\r\n"));
+        serde_json::to_writer(&mut self.events, &(time, "o", output))?;
+        writeln!(self.events)?;
+        self.shown += 1;
+        Ok(true)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.events.flush()?;
+        let header = Header {
+            version: 2,
+            width: self.width,
+            height: self.height,
+        };
+        let mut out = BufWriter::new(File::create(&self.path)?);
+        serde_json::to_writer(&mut out, &header)?;
+        writeln!(out)?;
+        io::copy(&mut File::open(&self.part)?, &mut out)?;
+        out.flush()?;
+        fs::remove_file(&self.part)
+    }
+}
+
+/// Every frame one after the other, each under a `--- frame N ---` line.
+struct FrameLog {
+    out: BufWriter<File>,
+    shown: usize,
+}
+
+impl Sink for FrameLog {
+    fn take(&mut self, frame: &str) -> io::Result<bool> {
+        self.shown += 1;
+        writeln!(self.out, "{FRAME_MARK}{} ---", self.shown)?;
+        self.out.write_all(frame.as_bytes())?;
+        if !frame.ends_with('\n') {
+            writeln!(self.out)?;
+        }
+        Ok(true)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// The frames of a frame log written by `--record`.
+pub fn read_frames(log: &str) -> Vec<String> {
+    let mut frames = Vec::new();
+    for line in log.lines() {
+        if line.starts_with(FRAME_MARK) {
+            frames.push(String::new());
+        } else if let Some(frame) = frames.last_mut() {
+            frame.push_str(line);
+            frame.push('\n');
+        }
+    }
+    frames
+}
+
+/// Sends the frames a day draws on to the terminal and any recordings,
+/// skipping all but every `every`th, until one of them has had enough or
+/// can't take any more.
+pub struct Projector {
+    sinks: Vec<Box<dyn Sink>>,
+    every: usize,
+    offered: usize,
+    stopped: bool,
+    error: Option<io::Error>,
+}
+
+impl Projector {
+    pub fn new(every: usize) -> Self {
+        Projector {
+            sinks: Vec::new(),
+            every,
+            offered: 0,
+            stopped: false,
+            error: None,
+        }
+    }
+
+    pub fn terminal(mut self, fps: u32) -> Self {
+        self.sinks.push(Box::new(Terminal::new(fps)));
+        self
+    }
+
+    /// Records to `path`: an asciicast when it ends in `.cast`, otherwise a
+    /// frame log.
+    pub fn record(mut self, path: &Path, fps: u32) -> Result<Self, String> {
+        let failed = |e: io::Error| format!("could not record to {}: {e}", path.display());
+        let sink: Box<dyn Sink> = match path.extension().is_some_and(|e| e == "cast") {
+            true => Box::new(Asciicast::create(path, fps).map_err(failed)?),
+            false => Box::new(FrameLog {
+                out: BufWriter::new(File::create(path).map_err(failed)?),
+                shown: 0,
+            }),
+        };
+        self.sinks.push(sink);
+        Ok(self)
+    }
+
+    /// Lets every sink tidy up, and reports the first thing that went wrong.
+    pub fn finish(mut self) -> Result<(), String> {
+        for sink in self.sinks.iter_mut() {
+            if let Err(e) = sink.finish() {
+                self.error.get_or_insert(e);
+            }
+        }
+        match self.error {
+            Some(e) => Err(format!("could not show every frame: {e}")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Screen for Projector {
+    fn show(&mut self, frame: &str) -> bool {
+        if self.stopped {
+            return false;
+        }
+        let skipped = !self.offered.is_multiple_of(self.every);
+        self.offered += 1;
+        if skipped {
+            return true;
+        }
+        for sink in self.sinks.iter_mut() {
+            match sink.take(frame) {
+                Ok(true) => (),
+                Ok(false) => self.stopped = true,
+                Err(e) => {
+                    self.error.get_or_insert(e);
+                    self.stopped = true;
+                }
+            }
+            if self.stopped {
+                break;
+            }
+        }
+        !self.stopped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control() {
+        assert_eq!(control("p\n"), Some(Control::Pause));
+        assert_eq!(control(""), Some(Control::Step));
+        assert_eq!(control(" n "), Some(Control::Step));
+        assert_eq!(control("q"), Some(Control::Quit));
+        assert_eq!(control("quit"), None);
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = std::env::temp_dir().join(format!("aoc-visualize-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let (log, cast) = (dir.join("frames.txt"), dir.join("frames.cast"));
+        let mut projector = Projector::new(2)
+            .record(&log, 10)
+            .unwrap()
+            .record(&cast, 10)
+            .unwrap();
+        for frame in ["#..\n", ".#.\n", "..#\n...\n", "...", "###\n"] {
+            assert!(projector.show(frame));
+        }
+        projector.finish().unwrap();
+
+        let frames = read_frames(&fs::read_to_string(&log).unwrap());
+        assert_eq!(frames, ["#..\n", "..#\n...\n", "###\n"]);
+
+        let cast = fs::read_to_string(&cast).unwrap();
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], r#"{"version":2,"width":3,"height":2}"#);
+        let event: (f64, String, String) = serde_json::from_str(lines[2]).unwrap();
+        assert_eq!(
+            event,
+            (0.1, "o".to_string(), format!("{CLEAR}..#\r\n...\r\n"))
+        );
+        assert!(!dir.join("frames.cast.part").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
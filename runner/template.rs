@@ -0,0 +1,36 @@
+use aoc::{ParseError, Solution, Unsolved};
+
+pub struct DayDAY {
+    lines: Vec<String>,
+}
+
+impl Solution for DayDAY {
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(DayDAY {
+            lines: aoc::lines(input),
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn part2(&self) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE: &str = include_str!("../input/DAY-sample-1.txt");
+
+    #[test]
+    fn test_parse() {
+        let day = DayDAY::parse(SAMPLE).unwrap();
+        assert_eq!(day.lines.len(), SAMPLE.lines().count());
+    }
+}